@@ -415,9 +415,17 @@ fn parse_sap_ssr_client(document: &str) -> Result<SapSsrClient, BodyError> {
 }
 
 #[cfg(test)]
-mod test {
+pub(crate) mod test {
     use super::*;
 
+    /// 주어진 HTML과 SSR 폼을 가진 문서를 생성합니다.
+    pub(crate) fn body(content: &str) -> Body {
+        Body::new(format!(
+            r#"<html><body>{content}<form id="sap.client.SsrClient.form" action="/sap/bc/webdynpro/SAP/TEST"><input id="sap-charset" value="utf-8"><input id="sap-wd-secure-id" value="SECURE"><input id="fesrAppName" value="TEST"><input id="fesrUseBeacon" value="false"></form></body></html>"#
+        ))
+        .unwrap()
+    }
+
     #[cfg(feature = "reqwest")]
     #[tokio::test]
     async fn test_ssr_form() {
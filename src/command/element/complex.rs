@@ -1,12 +1,23 @@
 use crate::element::parser::ElementParser;
+use crate::element::sub::SubElement as _;
+use crate::element::sub::definition::SubElementDefinition as _;
 use crate::event::Event;
 use crate::{
     command::WebDynproCommand,
     element::{
         Element,
-        complex::{SapTableDef, SapTableLSData, sap_table::SapTableBody},
+        complex::{
+            SapTableDef, SapTableLSData,
+            sap_table::{
+                SapTableBody, SapTableRow,
+                cell::{SapTableCellDefWrapper, SapTableHeaderCellLSData},
+                property::AccessType,
+            },
+        },
+        definition::ElementDefinition,
+        property::SortState,
     },
-    error::WebDynproError,
+    error::{ElementError, WebDynproError},
 };
 
 /// 주어진 [`SapTable`](crate::element::complex::SapTable)의 상하 스크롤을 수행하는 이벤트를 반환
@@ -104,3 +115,448 @@ impl WebDynproCommand for SapTableBodyCommand {
         Ok(body)
     }
 }
+
+fn header_cell_id(
+    parser: &ElementParser,
+    element_def: &SapTableDef,
+    column_title: &str,
+    event: &str,
+    is_allowed: impl FnOnce(&SapTableHeaderCellLSData) -> bool,
+) -> Result<String, WebDynproError> {
+    let table = parser.element_from_def(element_def)?;
+    let header = table
+        .table()?
+        .header()
+        .ok_or_else(|| ElementError::NoSuchContent {
+            element: element_def.id().to_string(),
+            content: "Header of table".to_string(),
+        })?;
+    let SapTableCellDefWrapper::Header(cell_def) = header.cell_by_title(column_title, parser)?
+    else {
+        Err(ElementError::InvalidContent {
+            element: element_def.id().to_string(),
+            content: format!("Header cell with title {column_title}"),
+        })?
+    };
+    let cell = parser.subelement_from_def(cell_def)?;
+    if !is_allowed(cell.lsdata()) {
+        Err(ElementError::InvalidContent {
+            element: cell_def.id().to_string(),
+            content: format!("Column {column_title} which allows {event}"),
+        })?
+    }
+    Ok(cell_def.id().to_string())
+}
+
+/// 주어진 [`SapTable`](crate::element::complex::SapTable)의 열을 제목으로 찾아 정렬하는 이벤트를 반환
+///
+/// 정렬할 수 없는 열(`SortState::Disabled`)이거나 주어진 정렬 상태가 `SortState::Disabled`라면 [`ElementError::InvalidContent`] 오류를 반환합니다.
+pub struct SapTableSortEventCommand {
+    element_def: SapTableDef,
+    column_title: String,
+    sort_state: SortState,
+}
+
+impl SapTableSortEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: SapTableDef, column_title: &str, sort_state: SortState) -> Self {
+        Self {
+            element_def,
+            column_title: column_title.to_string(),
+            sort_state,
+        }
+    }
+}
+
+impl WebDynproCommand for SapTableSortEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        if matches!(self.sort_state, SortState::Disabled) {
+            Err(ElementError::InvalidContent {
+                element: self.element_def.id().to_string(),
+                content: format!("Sort state {}", self.sort_state),
+            })?
+        }
+        let cell_id = header_cell_id(
+            parser,
+            &self.element_def,
+            &self.column_title,
+            "Sort",
+            |lsdata| !matches!(lsdata.sort_state(), Some(SortState::Disabled)),
+        )?;
+        parser
+            .element_from_def(&self.element_def)?
+            .sort(&cell_id, self.sort_state.clone())
+    }
+}
+
+/// 주어진 [`SapTable`](crate::element::complex::SapTable)의 열을 제목으로 찾아 필터를 적용하는 이벤트를 반환
+pub struct SapTableFilterEventCommand {
+    element_def: SapTableDef,
+    column_title: String,
+    value: String,
+}
+
+impl SapTableFilterEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: SapTableDef, column_title: &str, value: &str) -> Self {
+        Self {
+            element_def,
+            column_title: column_title.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for SapTableFilterEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        let cell_id = header_cell_id(
+            parser,
+            &self.element_def,
+            &self.column_title,
+            "Filter",
+            |_| true,
+        )?;
+        parser
+            .element_from_def(&self.element_def)?
+            .filter(&cell_id, &self.value)
+    }
+}
+
+/// 주어진 [`SapTable`](crate::element::complex::SapTable)의 열을 제목으로 찾아 필터를 해제하는 이벤트를 반환
+///
+/// 필터가 적용되지 않은 열이라면 [`ElementError::InvalidContent`] 오류를 반환합니다.
+pub struct SapTableClearFilterEventCommand {
+    element_def: SapTableDef,
+    column_title: String,
+}
+
+impl SapTableClearFilterEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: SapTableDef, column_title: &str) -> Self {
+        Self {
+            element_def,
+            column_title: column_title.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for SapTableClearFilterEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        let cell_id = header_cell_id(
+            parser,
+            &self.element_def,
+            &self.column_title,
+            "Filter",
+            |lsdata| lsdata.filtered().copied().unwrap_or(false),
+        )?;
+        parser
+            .element_from_def(&self.element_def)?
+            .clear_filter(&cell_id)
+    }
+}
+
+/// 주어진 [`SapTable`](crate::element::complex::SapTable)의 열을 제목으로 찾아 선택하는 이벤트를 반환
+///
+/// 선택할 수 없는 열(`column_selectable`이 참이 아닌 열)이라면 [`ElementError::InvalidContent`] 오류를 반환합니다.
+pub struct SapTableColumnSelectEventCommand {
+    element_def: SapTableDef,
+    column_title: String,
+    access_type: AccessType,
+}
+
+impl SapTableColumnSelectEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: SapTableDef, column_title: &str, access_type: AccessType) -> Self {
+        Self {
+            element_def,
+            column_title: column_title.to_string(),
+            access_type,
+        }
+    }
+}
+
+impl WebDynproCommand for SapTableColumnSelectEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        let cell_id = header_cell_id(
+            parser,
+            &self.element_def,
+            &self.column_title,
+            "ColSelect",
+            |lsdata| lsdata.column_selectable().copied().unwrap_or(false),
+        )?;
+        parser
+            .element_from_def(&self.element_def)?
+            .column_select(&cell_id, self.access_type.clone())
+    }
+}
+
+/// 주어진 [`SapTable`](crate::element::complex::SapTable)의 계층형 행을 펼치거나 접는 이벤트를 반환
+pub struct SapTableHierarchyToggleEventCommand {
+    element_def: SapTableDef,
+    row_index: u32,
+    expand: bool,
+}
+
+impl SapTableHierarchyToggleEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: SapTableDef, row_index: u32, expand: bool) -> Self {
+        Self {
+            element_def,
+            row_index,
+            expand,
+        }
+    }
+}
+
+impl WebDynproCommand for SapTableHierarchyToggleEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        let table = parser.element_from_def(&self.element_def)?;
+        let body = table.table()?;
+        let hierarchy = body.hierarchy(parser)?;
+        let Some(target) = hierarchy
+            .iter()
+            .find(|row| row.row().row_index() == Some(self.row_index))
+        else {
+            return Err(ElementError::NoSuchContent {
+                element: self.element_def.id().to_string(),
+                content: format!("Row {}", self.row_index),
+            })?;
+        };
+        let Some(cell_id) = target.cell_id() else {
+            return Err(ElementError::NoSuchContent {
+                element: self.element_def.id().to_string(),
+                content: format!("Hierarchical cell of row {}", self.row_index),
+            })?;
+        };
+        table.toggle_hierarchical(self.row_index, cell_id, self.expand)
+    }
+}
+
+/// 주어진 [`SapTable`](crate::element::complex::SapTable)의 행 선택 상태를 반전하는 이벤트를 반환
+pub struct SapTableRowSelectionToggleEventCommand {
+    element_def: SapTableDef,
+    row_index: u32,
+}
+
+impl SapTableRowSelectionToggleEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: SapTableDef, row_index: u32) -> Self {
+        Self {
+            element_def,
+            row_index,
+        }
+    }
+}
+
+impl WebDynproCommand for SapTableRowSelectionToggleEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        let table = parser.element_from_def(&self.element_def)?;
+        let Some(row) = table
+            .table()?
+            .iter()
+            .find(|row| row.row_index() == Some(self.row_index))
+        else {
+            return Err(ElementError::NoSuchContent {
+                element: self.element_def.id().to_string(),
+                content: format!("Row {}", self.row_index),
+            })?;
+        };
+        let Some(selection_cell) = row.selection_cell() else {
+            return Err(ElementError::NoSuchContent {
+                element: self.element_def.id().to_string(),
+                content: format!("Selection cell of row {}", self.row_index),
+            })?;
+        };
+        table.toggle_row_selection(
+            self.row_index as i32,
+            row.user_data().unwrap_or_default(),
+            selection_cell.id(),
+        )
+    }
+}
+
+fn selection_header_cell_id(
+    parser: &ElementParser,
+    element_def: &SapTableDef,
+) -> Result<String, WebDynproError> {
+    let table = parser.element_from_def(element_def)?;
+    let header = table
+        .table()?
+        .header()
+        .ok_or_else(|| ElementError::NoSuchContent {
+            element: element_def.id().to_string(),
+            content: "Header of table".to_string(),
+        })?;
+    let cell = header
+        .selection_cell(parser)
+        .ok_or_else(|| ElementError::NoSuchContent {
+            element: element_def.id().to_string(),
+            content: "Selection column header".to_string(),
+        })?;
+    Ok(cell.id().to_string())
+}
+
+/// 주어진 [`SapTable`](crate::element::complex::SapTable)의 모든 행을 선택하는 이벤트를 반환
+pub struct SapTableSelectAllEventCommand {
+    element_def: SapTableDef,
+}
+
+impl SapTableSelectAllEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: SapTableDef) -> Self {
+        Self { element_def }
+    }
+}
+
+impl WebDynproCommand for SapTableSelectAllEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        let cell_id = selection_header_cell_id(parser, &self.element_def)?;
+        parser
+            .element_from_def(&self.element_def)?
+            .select_all(&cell_id)
+    }
+}
+
+/// 주어진 [`SapTable`](crate::element::complex::SapTable)의 모든 행의 선택을 해제하는 이벤트를 반환
+pub struct SapTableDeselectAllEventCommand {
+    element_def: SapTableDef,
+}
+
+impl SapTableDeselectAllEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: SapTableDef) -> Self {
+        Self { element_def }
+    }
+}
+
+impl WebDynproCommand for SapTableDeselectAllEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        let cell_id = selection_header_cell_id(parser, &self.element_def)?;
+        parser
+            .element_from_def(&self.element_def)?
+            .deselect_all(&cell_id)
+    }
+}
+
+/// 주어진 [`SapTable`](crate::element::complex::SapTable)에서 선택된 행들을 반환
+pub struct SapTableSelectedRowsCommand {
+    element_def: SapTableDef,
+}
+
+impl SapTableSelectedRowsCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: SapTableDef) -> Self {
+        Self { element_def }
+    }
+}
+
+impl WebDynproCommand for SapTableSelectedRowsCommand {
+    type Result = Vec<SapTableRow>;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        let table = parser.element_from_def(&self.element_def)?;
+        Ok(table.table()?.selected_rows().cloned().collect())
+    }
+}
+
+/// 주어진 [`SapTable`](crate::element::complex::SapTable)의 행과 열 위치에 있는 셀의 값을 변경하는 이벤트를 반환
+pub struct SapTableEditCellEventCommand {
+    element_def: SapTableDef,
+    row: usize,
+    col: usize,
+    value: String,
+}
+
+impl SapTableEditCellEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: SapTableDef, row: usize, col: usize, value: &str) -> Self {
+        Self {
+            element_def,
+            row,
+            col,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for SapTableEditCellEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .table()?
+            .edit_cell(self.row, self.col, &self.value, parser)
+    }
+}
+
+/// 주어진 [`SapTable`](crate::element::complex::SapTable)의 행과 열 위치에 있는 셀이 읽기 전용인지 여부를 반환
+pub struct SapTableCellReadonlyCommand {
+    element_def: SapTableDef,
+    row: usize,
+    col: usize,
+}
+
+impl SapTableCellReadonlyCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: SapTableDef, row: usize, col: usize) -> Self {
+        Self {
+            element_def,
+            row,
+            col,
+        }
+    }
+}
+
+impl WebDynproCommand for SapTableCellReadonlyCommand {
+    type Result = bool;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .table()?
+            .is_cell_readonly(self.row, self.col, parser)
+    }
+}
+
+/// 주어진 [`SapTable`](crate::element::complex::SapTable)의 좌우 스크롤을 수행하는 이벤트를 반환
+pub struct SapTableHorizontalScrollEventCommand {
+    element_def: SapTableDef,
+    first_visible_col_index: u32,
+}
+
+impl SapTableHorizontalScrollEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: SapTableDef, first_visible_col_index: u32) -> Self {
+        Self {
+            element_def,
+            first_visible_col_index,
+        }
+    }
+}
+
+impl WebDynproCommand for SapTableHorizontalScrollEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .horizontal_scroll(self.first_visible_col_index)
+    }
+}
@@ -3,8 +3,12 @@ use crate::event::Event;
 use crate::{
     command::WebDynproCommand,
     element::{
+        Element as _,
         definition::ElementDefinition,
-        layout::{TabStripDef, tab_strip::item::TabStripItemDef},
+        layout::{
+            PopupWindowDef, ScrollContainerDef, ScrollbarDef, TabStripDef, TrayDef,
+            tab_strip::item::TabStripItemDef,
+        },
     },
     error::WebDynproError,
 };
@@ -45,3 +49,294 @@ impl WebDynproCommand for TabStripTabSelectEventCommand {
         )
     }
 }
+
+/// [`TabStrip`](crate::element::layout::TabStrip)에서 주어진 순서의 탭을 선택하도록 하는 이벤트를 반환
+pub struct TabStripSelectByIndexEventCommand {
+    element_def: TabStripDef,
+    index: usize,
+}
+
+impl TabStripSelectByIndexEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: TabStripDef, index: usize) -> Self {
+        Self { element_def, index }
+    }
+}
+
+impl WebDynproCommand for TabStripSelectByIndexEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .select_tab_by_index(self.index)
+    }
+}
+
+/// [`TabStrip`](crate::element::layout::TabStrip)에서 주어진 제목을 가진 탭을 선택하도록 하는 이벤트를 반환
+pub struct TabStripSelectByCaptionEventCommand {
+    element_def: TabStripDef,
+    caption: String,
+}
+
+impl TabStripSelectByCaptionEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: TabStripDef, caption: &str) -> Self {
+        Self {
+            element_def,
+            caption: caption.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for TabStripSelectByCaptionEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .select_tab_by_caption(&self.caption)
+    }
+}
+
+/// [`TabStrip`](crate::element::layout::TabStrip)에서 현재 선택된 탭의 [`TabStripItemDef`]를 반환
+pub struct TabStripSelectedTabCommand {
+    element_def: TabStripDef,
+}
+
+impl TabStripSelectedTabCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: TabStripDef) -> Self {
+        Self { element_def }
+    }
+}
+
+impl WebDynproCommand for TabStripSelectedTabCommand {
+    type Result = Option<TabStripItemDef>;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        Ok(parser
+            .element_from_def(&self.element_def)?
+            .selected_tab()
+            .map(|tab| TabStripItemDef::new_dynamic(tab.id().to_owned())))
+    }
+}
+
+/// 가장 위에 표시된 [`PopupWindow`](crate::element::layout::PopupWindow)의 [`PopupWindowDef`]를 반환
+pub struct ActivePopupWindowCommand;
+
+impl ActivePopupWindowCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ActivePopupWindowCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WebDynproCommand for ActivePopupWindowCommand {
+    type Result = Option<PopupWindowDef>;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        Ok(parser
+            .active_popup()
+            .map(|popup| PopupWindowDef::new_dynamic(popup.id().to_owned())))
+    }
+}
+
+/// [`PopupWindow`](crate::element::layout::PopupWindow)에서 주어진 텍스트를 가진 버튼을 누르는 이벤트를 반환
+pub struct PopupWindowPressButtonEventCommand {
+    element_def: PopupWindowDef,
+    text: String,
+}
+
+impl PopupWindowPressButtonEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: PopupWindowDef, text: &str) -> Self {
+        Self {
+            element_def,
+            text: text.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for PopupWindowPressButtonEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .press_button(&self.text)
+    }
+}
+
+/// [`Tray`](crate::element::layout::Tray)를 펼치거나 접는 이벤트를 반환
+pub struct TrayToggleEventCommand {
+    element_def: TrayDef,
+    expand: bool,
+}
+
+impl TrayToggleEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: TrayDef, expand: bool) -> Self {
+        Self {
+            element_def,
+            expand,
+        }
+    }
+}
+
+impl WebDynproCommand for TrayToggleEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .toggle(self.expand)
+    }
+}
+
+/// [`Tray`](crate::element::layout::Tray)를 닫는 이벤트를 반환
+pub struct TrayCloseEventCommand {
+    element_def: TrayDef,
+}
+
+impl TrayCloseEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: TrayDef) -> Self {
+        Self { element_def }
+    }
+}
+
+impl WebDynproCommand for TrayCloseEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser.element_from_def(&self.element_def)?.close()
+    }
+}
+
+/// [`Tray`](crate::element::layout::Tray)의 옵션 메뉴를 여는 이벤트를 반환
+pub struct TrayShowOptionMenuEventCommand {
+    element_def: TrayDef,
+}
+
+impl TrayShowOptionMenuEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: TrayDef) -> Self {
+        Self { element_def }
+    }
+}
+
+impl WebDynproCommand for TrayShowOptionMenuEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .show_option_menu()
+    }
+}
+
+/// [`Tray`](crate::element::layout::Tray)의 내용이 렌더링되어 있는지 여부를 반환
+pub struct TrayContentRenderedCommand {
+    element_def: TrayDef,
+}
+
+impl TrayContentRenderedCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: TrayDef) -> Self {
+        Self { element_def }
+    }
+}
+
+impl WebDynproCommand for TrayContentRenderedCommand {
+    type Result = bool;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        Ok(parser
+            .element_from_def(&self.element_def)?
+            .is_content_rendered())
+    }
+}
+
+/// [`Scrollbar`](crate::element::layout::Scrollbar)의 값을 변경하는 이벤트를 반환
+pub struct ScrollbarScrollEventCommand {
+    element_def: ScrollbarDef,
+    value: i32,
+}
+
+impl ScrollbarScrollEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: ScrollbarDef, value: i32) -> Self {
+        Self { element_def, value }
+    }
+}
+
+impl WebDynproCommand for ScrollbarScrollEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .scroll(self.value)
+    }
+}
+
+/// [`Scrollbar`](crate::element::layout::Scrollbar)를 한 페이지 아래로 스크롤하는 이벤트를 반환
+///
+/// 이미 마지막 페이지라면 `None`을 반환합니다.
+pub struct ScrollbarNextPageEventCommand {
+    element_def: ScrollbarDef,
+}
+
+impl ScrollbarNextPageEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: ScrollbarDef) -> Self {
+        Self { element_def }
+    }
+}
+
+impl WebDynproCommand for ScrollbarNextPageEventCommand {
+    type Result = Option<Event>;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        let scrollbar = parser.element_from_def(&self.element_def)?;
+        scrollbar
+            .next_page_value()
+            .map(|value| scrollbar.scroll(value))
+            .transpose()
+    }
+}
+
+/// [`ScrollContainer`](crate::element::layout::ScrollContainer)의 스크롤 위치를 변경하는 이벤트를 반환
+pub struct ScrollContainerScrollEventCommand {
+    element_def: ScrollContainerDef,
+    scroll_top: i32,
+    scroll_left: i32,
+}
+
+impl ScrollContainerScrollEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: ScrollContainerDef, scroll_top: i32, scroll_left: i32) -> Self {
+        Self {
+            element_def,
+            scroll_top,
+            scroll_left,
+        }
+    }
+}
+
+impl WebDynproCommand for ScrollContainerScrollEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .scroll(self.scroll_top, self.scroll_left)
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
         Element,
         definition::ElementDefinition,
         selection::{
-            ComboBoxDef, ComboBoxLSData,
+            CheckBoxDef, ComboBoxDef, ComboBoxLSData, ComboBoxTextMatch,
             list_box::{ListBoxDefWrapper, ListBoxWrapper, item::ListBoxItemInfo},
         },
     },
@@ -76,7 +76,8 @@ impl WebDynproCommand for ComboBoxChangeEventCommand {
 }
 
 /// [`ComboBox`](crate::element::selection::ComboBox)의 선택지를 `value1`의 값을 기반으로 선택하도록 하는 이벤트를 반환
-#[allow(unused)]
+///
+/// [`ComboBoxTextMatch::Exact`]를 사용하는 [`ComboBoxSelectByTextEventCommand`]와 같습니다.
 pub struct ComboBoxSelectByValue1EventCommand {
     element_def: ComboBoxDef,
     value: String,
@@ -102,33 +103,163 @@ impl WebDynproCommand for ComboBoxSelectByValue1EventCommand {
     type Result = Event;
 
     fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
-        let listbox_def = parser.read(ComboBoxItemListBoxCommand::new(self.element_def.clone()))?;
-        let items = parser.read(ListBoxItemInfoCommand::new(listbox_def))?;
-        let item_key = items
-            .iter()
-            .find_map(|info| match info {
-                ListBoxItemInfo::Item { value1, key, .. } => {
-                    if value1 == &self.value {
-                        Some(key)
-                    } else {
-                        None
-                    }
-                }
-                _ => None,
-            })
-            .ok_or(ElementError::InvalidContent {
-                element: self.element_def.id().to_string(),
-                content: format!("Cannot find {} option", self.value),
-            })?
-            .to_owned();
-        parser.read(ComboBoxSelectEventCommand::new(
+        parser.read(ComboBoxSelectByTextEventCommand::new(
             self.element_def.clone(),
-            &item_key,
-            false,
+            &self.value,
+            ComboBoxTextMatch::Exact,
+            self.by_enter,
         ))
     }
 }
 
+/// [`ComboBox`](crate::element::selection::ComboBox)의 선택지를 표시되는 텍스트를 기반으로 선택하도록 하는 이벤트를 반환
+///
+/// 일치하는 선택지가 없다면 선택 가능한 선택지의 목록을 담은 [`ElementError::NoSuchOption`] 오류를 반환합니다.
+pub struct ComboBoxSelectByTextEventCommand {
+    element_def: ComboBoxDef,
+    text: String,
+    match_mode: ComboBoxTextMatch,
+    by_enter: bool,
+}
+
+impl ComboBoxSelectByTextEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(
+        element_def: ComboBoxDef,
+        text: &str,
+        match_mode: ComboBoxTextMatch,
+        by_enter: bool,
+    ) -> ComboBoxSelectByTextEventCommand {
+        Self {
+            element_def,
+            text: text.to_string(),
+            match_mode,
+            by_enter,
+        }
+    }
+}
+
+impl WebDynproCommand for ComboBoxSelectByTextEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser.element_from_def(&self.element_def)?.select_by_text(
+            &self.text,
+            self.match_mode,
+            self.by_enter,
+            parser,
+        )
+    }
+}
+
+/// [`ComboBox`](crate::element::selection::ComboBox)에서 표시되는 텍스트와 일치하는 선택지의 키를 반환
+pub struct ComboBoxKeyForTextCommand {
+    element_def: ComboBoxDef,
+    text: String,
+    match_mode: ComboBoxTextMatch,
+}
+
+impl ComboBoxKeyForTextCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: ComboBoxDef, text: &str, match_mode: ComboBoxTextMatch) -> Self {
+        Self {
+            element_def,
+            text: text.to_string(),
+            match_mode,
+        }
+    }
+}
+
+impl WebDynproCommand for ComboBoxKeyForTextCommand {
+    type Result = String;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser.element_from_def(&self.element_def)?.key_for_text(
+            &self.text,
+            self.match_mode,
+            parser,
+        )
+    }
+}
+
+/// [`ComboBox`](crate::element::selection::ComboBox)에서 주어진 키를 가진 선택지의 표시되는 텍스트를 반환
+pub struct ComboBoxTextForKeyCommand {
+    element_def: ComboBoxDef,
+    key: String,
+}
+
+impl ComboBoxTextForKeyCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: ComboBoxDef, key: &str) -> Self {
+        Self {
+            element_def,
+            key: key.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for ComboBoxTextForKeyCommand {
+    type Result = String;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .text_for_key(&self.key, parser)
+    }
+}
+
+/// [`ComboBox`](crate::element::selection::ComboBox)에 입력 중인 값에 대한 추천 목록을 요청하는 이벤트를 반환
+pub struct ComboBoxSuggestEventCommand {
+    element_def: ComboBoxDef,
+    value: String,
+}
+
+impl ComboBoxSuggestEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: ComboBoxDef, value: &str) -> Self {
+        Self {
+            element_def,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for ComboBoxSuggestEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .suggest(&self.value)
+    }
+}
+
+/// [`ComboBox`](crate::element::selection::ComboBox)에서 입력한 값에 대한 추천 선택지 목록을 반환
+pub struct ComboBoxSuggestionsCommand {
+    element_def: ComboBoxDef,
+    value: String,
+}
+
+impl ComboBoxSuggestionsCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: ComboBoxDef, value: &str) -> Self {
+        Self {
+            element_def,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for ComboBoxSuggestionsCommand {
+    type Result = Vec<ListBoxItemInfo>;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .suggestions(&self.value, parser)
+    }
+}
+
 /// [`ComboBoxLSData`]를 반환
 pub struct ComboBoxLSDataCommand {
     element_def: ComboBoxDef,
@@ -246,3 +377,109 @@ impl WebDynproCommand for ListBoxItemInfoCommand {
         }
     }
 }
+
+/// 주어진 [`CheckBox`](crate::element::selection::CheckBox)의 체크 상태를 바꾸는 이벤트를 반환
+///
+/// 체크박스가 비활성화 상태이거나 읽기 전용일 경우 [`ElementError::InvalidContent`] 오류를 반환합니다.
+pub struct CheckBoxToggleEventCommand {
+    element_def: CheckBoxDef,
+    checked: bool,
+}
+
+impl CheckBoxToggleEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: CheckBoxDef, checked: bool) -> Self {
+        Self {
+            element_def,
+            checked,
+        }
+    }
+}
+
+impl WebDynproCommand for CheckBoxToggleEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        let check_box = parser.element_from_def(&self.element_def)?;
+        if check_box.disabled() || check_box.readonly() {
+            return Err(ElementError::InvalidContent {
+                element: self.element_def.id().to_owned(),
+                content: "CheckBox is disabled or readonly".to_string(),
+            })?;
+        }
+        check_box.toggle(self.checked)
+    }
+}
+
+/// [`ListBox`](crate::element::selection::list_box::ListBox)의 주어진 키를 가진 아이템을 선택하는 이벤트를 반환
+pub struct ListBoxSelectEventCommand {
+    element_def: ListBoxDefWrapper,
+    key: String,
+}
+
+impl ListBoxSelectEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: ListBoxDefWrapper, key: &str) -> Self {
+        Self {
+            element_def,
+            key: key.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for ListBoxSelectEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        self.element_def.value(parser)?.select(&self.key)
+    }
+}
+
+/// [`ListBox`](crate::element::selection::list_box::ListBox)의 주어진 키를 가진 아이템의 선택을 해제하는 이벤트를 반환
+pub struct ListBoxDeselectEventCommand {
+    element_def: ListBoxDefWrapper,
+    key: String,
+}
+
+impl ListBoxDeselectEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: ListBoxDefWrapper, key: &str) -> Self {
+        Self {
+            element_def,
+            key: key.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for ListBoxDeselectEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        self.element_def.value(parser)?.deselect(&self.key)
+    }
+}
+
+/// [`ListBoxMultiple`](crate::element::selection::list_box::ListBoxMultiple)의 주어진 키들을 가진 아이템들을 선택하는 이벤트를 반환
+pub struct ListBoxMultiSelectEventCommand {
+    element_def: ListBoxDefWrapper,
+    keys: Vec<String>,
+}
+
+impl ListBoxMultiSelectEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: ListBoxDefWrapper, keys: &[&str]) -> Self {
+        Self {
+            element_def,
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+        }
+    }
+}
+
+impl WebDynproCommand for ListBoxMultiSelectEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        let keys = self.keys.iter().map(String::as_str).collect::<Vec<_>>();
+        self.element_def.value(parser)?.multi_select(&keys)
+    }
+}
@@ -1,7 +1,13 @@
 use crate::element::parser::ElementParser;
+use crate::event::Event;
 use crate::{
     command::WebDynproCommand,
-    element::{definition::ElementDefinition, text::InputFieldDef},
+    element::{
+        Element,
+        definition::ElementDefinition,
+        selection::list_box::item::ListBoxItemInfo,
+        text::{InputFieldDef, InputFieldLSData},
+    },
     error::{ElementError, WebDynproError},
 };
 
@@ -32,3 +38,151 @@ impl WebDynproCommand for InputFieldValueCommand {
         Ok(text)
     }
 }
+
+/// [`InputField`](crate::element::text::InputField)의 [`InputFieldLSData`]를 반환
+pub struct InputFieldLSDataCommand {
+    element_def: InputFieldDef,
+}
+
+impl InputFieldLSDataCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: InputFieldDef) -> Self {
+        Self { element_def }
+    }
+}
+
+impl WebDynproCommand for InputFieldLSDataCommand {
+    type Result = InputFieldLSData;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        let lsdata = parser.element_from_def(&self.element_def)?.lsdata().clone();
+        Ok(lsdata)
+    }
+}
+
+/// [`InputField`](crate::element::text::InputField)의 값을 바꾸는 이벤트를 반환
+///
+/// 읽기 전용이거나 비활성화된 입력 필드일 경우 [`ElementError::InvalidContent`] 오류를 반환합니다.
+pub struct InputFieldChangeEventCommand {
+    element_def: InputFieldDef,
+    value: String,
+}
+
+impl InputFieldChangeEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: InputFieldDef, value: &str) -> Self {
+        Self {
+            element_def,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for InputFieldChangeEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        let input_field = parser.element_from_def(&self.element_def)?;
+        if input_field.readonly() || input_field.disabled() {
+            return Err(ElementError::InvalidContent {
+                element: self.element_def.id().to_owned(),
+                content: "InputField is readonly or disabled".to_string(),
+            })?;
+        }
+        input_field.change(&self.value)
+    }
+}
+
+/// [`InputField`](crate::element::text::InputField)에서 엔터를 누르는 이벤트를 반환
+pub struct InputFieldEnterEventCommand {
+    element_def: InputFieldDef,
+}
+
+impl InputFieldEnterEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: InputFieldDef) -> Self {
+        Self { element_def }
+    }
+}
+
+impl WebDynproCommand for InputFieldEnterEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser.element_from_def(&self.element_def)?.enter()
+    }
+}
+
+/// [`InputField`](crate::element::text::InputField)의 값 도움말(F4)을 요청하는 이벤트를 반환
+pub struct InputFieldValueHelpRequestEventCommand {
+    element_def: InputFieldDef,
+}
+
+impl InputFieldValueHelpRequestEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: InputFieldDef) -> Self {
+        Self { element_def }
+    }
+}
+
+impl WebDynproCommand for InputFieldValueHelpRequestEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .value_help_request()
+    }
+}
+
+/// [`InputField`](crate::element::text::InputField)에 입력 중인 값에 대한 추천 목록을 요청하는 이벤트를 반환
+pub struct InputFieldSuggestEventCommand {
+    element_def: InputFieldDef,
+    value: String,
+}
+
+impl InputFieldSuggestEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: InputFieldDef, value: &str) -> Self {
+        Self {
+            element_def,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for InputFieldSuggestEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .suggest(&self.value)
+    }
+}
+
+/// [`InputField`](crate::element::text::InputField)에 표시된 추천 아이템 목록을 반환
+pub struct InputFieldSuggestionsCommand {
+    element_def: InputFieldDef,
+    value: String,
+}
+
+impl InputFieldSuggestionsCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: InputFieldDef, value: &str) -> Self {
+        Self {
+            element_def,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for InputFieldSuggestionsCommand {
+    type Result = Vec<ListBoxItemInfo>;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .suggestions(&self.value, parser)
+    }
+}
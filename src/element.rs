@@ -1,5 +1,4 @@
-use std::collections::HashMap;
-
+use indexmap::IndexMap;
 use regex_lite::Regex;
 use scraper::ElementRef;
 use selection::CheckBox;
@@ -64,7 +63,7 @@ pub mod property;
 pub mod registry;
 
 /// 엘리먼트에서 발생시킬 수 있는 이벤트의 기본 파라메터
-pub type EventParameterMap = HashMap<String, (UcfParameters, HashMap<String, String>)>;
+pub type EventParameterMap = IndexMap<String, (UcfParameters, IndexMap<String, String>)>;
 
 macro_rules! element_wrapper_impls {
     [$( $enum:ident : $type: ty ),+ $(,)?] => {
@@ -319,9 +318,9 @@ pub trait Interactable<'a>: Element<'a> {
     /// 엘리먼트가 이벤트를 발생시킬 수 있는지 여부를 확인하고 이 함수를 호출해야 합니다.
     unsafe fn fire_event_unchecked(
         event: String,
-        parameters: HashMap<String, String>,
+        parameters: IndexMap<String, String>,
         ucf_params: UcfParameters,
-        custom_params: HashMap<String, String>,
+        custom_params: IndexMap<String, String>,
     ) -> Event {
         EventBuilder::default()
             .control(Self::ELEMENT_NAME.to_owned())
@@ -337,7 +336,7 @@ pub trait Interactable<'a>: Element<'a> {
     fn event_parameter(
         &self,
         event: &str,
-    ) -> Result<&(UcfParameters, HashMap<String, String>), ElementError> {
+    ) -> Result<&(UcfParameters, IndexMap<String, String>), ElementError> {
         if let Some(lsevents) = self.lsevents() {
             lsevents.get(event).ok_or(ElementError::NoSuchEvent {
                 element: self.id().to_string(),
@@ -355,7 +354,7 @@ pub trait Interactable<'a>: Element<'a> {
     fn fire_event(
         &self,
         event: String,
-        parameters: HashMap<String, String>,
+        parameters: IndexMap<String, String>,
     ) -> Result<Event, WebDynproError> {
         let (ucf_params, custom_params) = self.event_parameter(&event)?;
         Ok(unsafe {
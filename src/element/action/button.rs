@@ -2,7 +2,10 @@ use std::{borrow::Cow, cell::OnceCell};
 
 use crate::{
     WdElement, WdLsData,
-    element::property::{ContentVisibility, HotkeyValue, SemanticColor, TextDesign, Visibility},
+    element::{
+        Element as _,
+        property::{ContentVisibility, HotkeyValue, SemanticColor, TextDesign, Visibility},
+    },
     wd_event,
 };
 
@@ -152,4 +155,22 @@ impl<'a> Button<'a> {
     /// 버튼 누름 이벤트를 반환합니다.
     #[wd_event(name = "Press")]
     pub fn press(&self) {}
+
+    /// 버튼에 표시되는 텍스트를 반환합니다. 내부 데이터에 텍스트가 없다면 엘리먼트의 텍스트를 반환합니다.
+    pub fn text(&self) -> String {
+        match self.lsdata().text() {
+            Some(text) if !text.trim().is_empty() => text.trim().to_string(),
+            _ => self
+                .element_ref()
+                .text()
+                .collect::<String>()
+                .trim()
+                .to_string(),
+        }
+    }
+
+    /// 이 [`Button`]이 활성화되어 있는지 여부를 반환합니다.
+    pub fn enabled(&self) -> bool {
+        self.lsdata().enabled().copied().unwrap_or(true)
+    }
 }
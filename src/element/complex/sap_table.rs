@@ -1,10 +1,11 @@
-use std::{borrow::Cow, cell::OnceCell, collections::HashMap};
+use std::{borrow::Cow, cell::OnceCell};
 
+use indexmap::IndexMap;
 use scraper::Selector;
 
 use crate::{
     WdElement, WdLsData,
-    element::{Interactable, definition::ElementDefinition},
+    element::{Interactable, definition::ElementDefinition, property::SortState},
     error::{BodyError, ElementError, WebDynproError},
     event::Event,
 };
@@ -95,7 +96,7 @@ impl<'a> SapTable<'a> {
         access_type: AccessType,
         trigger_cell_id: &str,
     ) -> Result<Event, WebDynproError> {
-        let parameters: HashMap<String, String> = HashMap::from([
+        let parameters: IndexMap<String, String> = IndexMap::from([
             ("Id".to_string(), self.id.clone().to_string()),
             ("RowIndex".to_string(), format!("{row_index}")),
             ("RowUserData".to_string(), row_user_data.to_owned()),
@@ -106,6 +107,32 @@ impl<'a> SapTable<'a> {
         self.fire_event("RowSelect".to_string(), parameters)
     }
 
+    /// 주어진 행의 선택 셀을 눌러 행의 선택 상태를 반전하는 이벤트를 반환합니다.
+    pub fn toggle_row_selection(
+        &self,
+        row_index: i32,
+        row_user_data: &str,
+        selection_cell_id: &str,
+    ) -> Result<Event, WebDynproError> {
+        self.row_select(
+            row_index,
+            row_user_data,
+            "",
+            AccessType::Toggle,
+            selection_cell_id,
+        )
+    }
+
+    /// 선택 열 헤더의 선택 메뉴로 모든 행을 선택하는 이벤트를 반환합니다.
+    pub fn select_all(&self, header_cell_id: &str) -> Result<Event, WebDynproError> {
+        self.row_select(-1, "", "", AccessType::SelectAll, header_cell_id)
+    }
+
+    /// 선택 열 헤더의 선택 메뉴로 모든 행의 선택을 해제하는 이벤트를 반환합니다.
+    pub fn deselect_all(&self, header_cell_id: &str) -> Result<Event, WebDynproError> {
+        self.row_select(-1, "", "", AccessType::DeselectAll, header_cell_id)
+    }
+
     /// 테이블의 내부 셀을 선택하는 이벤트를 반환합니다.
     #[allow(clippy::too_many_arguments)]
     pub fn cell_select(
@@ -118,7 +145,7 @@ impl<'a> SapTable<'a> {
         cell_user_data: &str,
         access_type: AccessType,
     ) -> Result<Event, WebDynproError> {
-        let parameters: HashMap<String, String> = HashMap::from([
+        let parameters: IndexMap<String, String> = IndexMap::from([
             ("Id".to_string(), self.id.clone().to_string()),
             ("CellId".to_string(), cell_id.to_owned()),
             ("CellType".to_string(), cell_type.to_owned()),
@@ -143,7 +170,7 @@ impl<'a> SapTable<'a> {
         ctrl: bool,
         alt: bool,
     ) -> Result<Event, WebDynproError> {
-        let parameters: HashMap<String, String> = HashMap::from([
+        let parameters: IndexMap<String, String> = IndexMap::from([
             ("Id".to_string(), self.id.clone().to_string()),
             (
                 "FirstVisibleItemIndex".to_string(),
@@ -161,19 +188,99 @@ impl<'a> SapTable<'a> {
         ]);
         self.fire_event("VerticalScroll".to_string(), parameters)
     }
+
+    /// 테이블을 좌우로 스크롤하여 `first_visible_col_index`번째 스크롤 가능한 열부터 보이게 하는 이벤트를 반환합니다.
+    pub fn horizontal_scroll(&self, first_visible_col_index: u32) -> Result<Event, WebDynproError> {
+        let parameters: IndexMap<String, String> = IndexMap::from([
+            ("Id".to_string(), self.id.clone().to_string()),
+            (
+                "FirstVisibleItemIndex".to_string(),
+                first_visible_col_index.to_string(),
+            ),
+        ]);
+        self.fire_event("HorizontalScroll".to_string(), parameters)
+    }
+
+    /// 계층형 테이블의 행을 펼치거나(`expand`가 참일 경우) 접는 이벤트를 반환합니다.
+    pub fn toggle_hierarchical(
+        &self,
+        row_index: u32,
+        cell_id: &str,
+        expand: bool,
+    ) -> Result<Event, WebDynproError> {
+        let parameters: IndexMap<String, String> = IndexMap::from([
+            ("Id".to_string(), self.id.clone().to_string()),
+            ("CellId".to_string(), cell_id.to_owned()),
+            ("RowIndex".to_string(), row_index.to_string()),
+            ("Expand".to_string(), expand.to_string()),
+        ]);
+        self.fire_event("ToggleHierarchical".to_string(), parameters)
+    }
+
+    /// 주어진 헤더 셀의 열을 정렬하는 이벤트를 반환합니다.
+    pub fn sort(&self, cell_id: &str, sort_state: SortState) -> Result<Event, WebDynproError> {
+        let parameters: IndexMap<String, String> = IndexMap::from([
+            ("Id".to_string(), self.id.clone().to_string()),
+            ("CellId".to_string(), cell_id.to_owned()),
+            ("SortState".to_string(), sort_state.to_string()),
+        ]);
+        self.fire_event("Sort".to_string(), parameters)
+    }
+
+    /// 주어진 헤더 셀의 열에 필터를 적용하는 이벤트를 반환합니다.
+    pub fn filter(&self, cell_id: &str, value: &str) -> Result<Event, WebDynproError> {
+        let parameters: IndexMap<String, String> = IndexMap::from([
+            ("Id".to_string(), self.id.clone().to_string()),
+            ("CellId".to_string(), cell_id.to_owned()),
+            ("Value".to_string(), value.to_owned()),
+        ]);
+        self.fire_event("Filter".to_string(), parameters)
+    }
+
+    /// 주어진 헤더 셀의 열에 적용된 필터를 해제하는 이벤트를 반환합니다.
+    ///
+    /// 별도의 해제 이벤트가 없으므로 필터 값을 비운 `Filter` 이벤트를 반환합니다.
+    pub fn clear_filter(&self, cell_id: &str) -> Result<Event, WebDynproError> {
+        self.filter(cell_id, "")
+    }
+
+    /// 주어진 헤더 셀의 열을 선택하는 이벤트를 반환합니다.
+    pub fn column_select(
+        &self,
+        cell_id: &str,
+        access_type: AccessType,
+    ) -> Result<Event, WebDynproError> {
+        let parameters: IndexMap<String, String> = IndexMap::from([
+            ("Id".to_string(), self.id.clone().to_string()),
+            ("CellId".to_string(), cell_id.to_owned()),
+            ("AccessType".to_string(), access_type.to_string()),
+        ]);
+        self.fire_event("ColSelect".to_string(), parameters)
+    }
 }
 
 mod body;
+mod de;
+mod export;
 mod from_sap_table;
+mod grid;
 mod header;
 mod row;
+mod stitch;
+
+#[cfg(test)]
+pub(crate) mod test;
 
 /// [`SapTable`] 내부 셀
 pub mod cell;
 /// [`SapTable`] 내부 데이터 프로퍼티
 pub mod property;
 
-pub use self::body::SapTableBody;
+pub use self::body::{SapTableBody, SapTableHierarchyRow};
+pub use self::de::{Deserialized, SapTableBodyDeserializer, SapTableRowDeserializer};
+pub use self::export::{SapTableExportOptions, SapTableExportOptionsBuilder};
 pub use self::from_sap_table::FromSapTable;
+pub use self::grid::{SapTableGrid, SapTableGridCell};
 pub use self::header::SapTableHeader;
 pub use self::row::SapTableRow;
+pub use self::stitch::SapTableColumnStitcher;
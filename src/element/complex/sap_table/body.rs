@@ -1,12 +1,19 @@
-use std::{collections::HashMap, ops::Index};
+use std::ops::Index;
 
 use scraper::ElementRef;
 
 use super::{
-    FromSapTable, SapTableDef, SapTableHeader, cell::SapTableCellDefWrapper,
-    property::SapTableRowType, row::SapTableRow,
+    FromSapTable, SapTableDef, SapTableHeader,
+    cell::{SapTableCell as _, SapTableCellDefWrapper, SapTableCellWrapper},
+    grid::{SapTableGrid, SapTableGridBuilder},
+    property::{SapTableHierarchicalCellStatus, SapTableRowType},
+    row::SapTableRow,
 };
+use crate::element::ElementDefWrapper;
 use crate::element::parser::ElementParser;
+use crate::element::selection::ComboBoxTextMatch;
+use crate::element::sub::{SubElement as _, definition::SubElementDefinition as _};
+use crate::event::Event;
 use crate::{
     element::definition::ElementDefinition,
     error::{ElementError, WebDynproError},
@@ -19,10 +26,10 @@ pub struct SapTableBody {
     table_def: SapTableDef,
     header: Option<SapTableHeader>,
     rows: Vec<SapTableRow>,
+    grid: SapTableGrid,
 }
 
 impl<'a> SapTableBody {
-    /// TODO: Implement unit test for rowspan/colspan handling
     pub(super) fn new(
         table_def: SapTableDef,
         elem_ref: ElementRef<'a>,
@@ -39,9 +46,7 @@ impl<'a> SapTableBody {
             });
         }
         let mut rows: Vec<SapTableRow> = Vec::new();
-        // Def, rowsize, colsize
-        type CellSpanInfo = (SapTableCellDefWrapper, u32, u32);
-        let mut spans: HashMap<u32, CellSpanInfo> = HashMap::new();
+        let mut grid = SapTableGridBuilder::new(table_def.clone());
         for row_ref in ref_iter.clone() {
             let row_type = row_ref
                 .value()
@@ -62,68 +67,41 @@ impl<'a> SapTableBody {
             }
             let subct_selector = scraper::Selector::parse("[subct]").unwrap();
             let subcts = row_ref.select(&subct_selector);
-            let mut cells: Vec<SapTableCellDefWrapper> = Vec::new();
-            let mut col_counter: u32 = 0;
             for cell_ref in subcts {
-                let cell = SapTableCellDefWrapper::from_ref(table_def.clone(), cell_ref);
-                if let Some(cell) = cell {
-                    if spans.contains_key(&col_counter) {
-                        let spanned_cell = spans.remove(&col_counter).unwrap();
-                        if spanned_cell.1 - 1 > 1 {
-                            spans.insert(
-                                col_counter,
-                                (spanned_cell.0.clone(), spanned_cell.1 - 1, spanned_cell.2),
-                            );
-                        }
-                        for _ in 0..spanned_cell.2 {
-                            col_counter += 1;
-                            cells.push(spanned_cell.0.clone());
-                        }
-                    }
-                    let cell_value = cell_ref.value();
-                    let rowspan = cell_value
-                        .attr("rowspan")
-                        .and_then(|str| str.parse::<u32>().ok())
-                        .unwrap_or(1);
-                    let colspan = cell_value
-                        .attr("colspan")
-                        .and_then(|str| str.parse::<u32>().ok())
-                        .unwrap_or(1);
-                    if rowspan > 1 {
-                        spans.insert(col_counter, (cell.clone(), rowspan, colspan));
-                    }
-                    for _ in 0..colspan {
-                        cells.push(cell.clone());
-                        col_counter += 1;
-                    }
-                }
-            }
-            // checks if spanning lasts after last cell
-            if spans.contains_key(&col_counter) {
-                let spanned_cell = spans.remove(&col_counter).unwrap();
-                if spanned_cell.1 - 1 > 1 {
-                    spans.insert(
-                        col_counter,
-                        (spanned_cell.0.clone(), spanned_cell.1 - 1, spanned_cell.2),
-                    );
-                }
-                #[allow(unused_assignments)]
-                for _ in 0..spanned_cell.2 {
-                    col_counter += 1;
-                    cells.push(spanned_cell.0.clone());
-                }
-            }
-            if let Ok(row) = SapTableRow::new(table_def.clone(), row_ref, cells) {
-                rows.push(row);
+                let Some(cell) = SapTableCellDefWrapper::from_ref(table_def.clone(), cell_ref)
+                else {
+                    continue;
+                };
+                let cell_value = cell_ref.value();
+                let rowspan = cell_value
+                    .attr("rowspan")
+                    .and_then(|str| str.parse::<u32>().ok())
+                    .unwrap_or(1);
+                let colspan = cell_value
+                    .attr("colspan")
+                    .and_then(|str| str.parse::<u32>().ok())
+                    .unwrap_or(1);
+                grid.push_cell(cell, rowspan, colspan);
             }
+            let cells = grid.finish_row();
+            rows.push(SapTableRow::new(table_def.clone(), row_ref, cells));
         }
         Ok(SapTableBody {
             table_def,
             header,
             rows,
+            grid: grid.build(),
         })
     }
 
+    /// 셀의 `rowspan`, `colspan` 정보를 보존하는 2차원 격자를 반환합니다.
+    ///
+    /// [`SapTableRow`]는 여러 칸을 차지하는 셀을 차지하는 칸마다 복제하여 가지지만,
+    /// [`SapTableGrid`]의 각 칸은 셀의 원점과 크기, 이어지는 칸인지 여부를 함께 가집니다.
+    pub fn grid(&self) -> &SapTableGrid {
+        &self.grid
+    }
+
     /// 헤더 행을 제외한 행의 갯수를 반환합니다.
     pub fn len(&self) -> usize {
         self.rows.len()
@@ -160,6 +138,158 @@ impl<'a> SapTableBody {
         self.header.as_ref()
     }
 
+    /// 주어진 위치의 행과 열에 있는 셀의 정의를 반환합니다.
+    pub fn cell(&self, row: usize, col: usize) -> Result<&SapTableCellDefWrapper, WebDynproError> {
+        self.rows
+            .get(row)
+            .and_then(|row| row.iter().nth(col))
+            .ok_or_else(|| {
+                ElementError::NoSuchContent {
+                    element: self.table_def.id().to_string(),
+                    content: format!("Cell at row {row}, column {col}"),
+                }
+                .into()
+            })
+    }
+
+    /// 주어진 위치의 셀 내부에 있는 엘리먼트의 정의를 반환합니다.
+    pub fn cell_element(
+        &self,
+        row: usize,
+        col: usize,
+        parser: &'a ElementParser,
+    ) -> Result<ElementDefWrapper<'a>, WebDynproError> {
+        let def = self.cell(row, col)?;
+        SapTableCellWrapper::from_def(def, parser)?
+            .content()
+            .ok_or_else(|| {
+                ElementError::NoSuchContent {
+                    element: def.id().to_string(),
+                    content: "Content of table cell".to_string(),
+                }
+                .into()
+            })
+    }
+
+    /// 주어진 위치의 셀 내부 엘리먼트가 값을 변경할 수 없는 상태라면 참을 반환합니다.
+    ///
+    /// [`InputField`](crate::element::text::InputField), [`ComboBox`](crate::element::selection::ComboBox), [`CheckBox`](crate::element::selection::CheckBox)가 아닌 엘리먼트는 항상 변경할 수 없는 것으로 취급합니다.
+    pub fn is_cell_readonly(
+        &self,
+        row: usize,
+        col: usize,
+        parser: &'a ElementParser,
+    ) -> Result<bool, WebDynproError> {
+        Ok(match self.cell_element(row, col, parser)? {
+            ElementDefWrapper::InputField(def) => {
+                let elem = parser.element_from_def(&def)?;
+                elem.readonly() || elem.disabled()
+            }
+            ElementDefWrapper::ComboBox(def) => {
+                let elem = parser.element_from_def(&def)?;
+                elem.readonly() || elem.disabled()
+            }
+            ElementDefWrapper::CheckBox(def) => {
+                let elem = parser.element_from_def(&def)?;
+                elem.readonly() || elem.disabled()
+            }
+            _ => true,
+        })
+    }
+
+    /// 주어진 위치의 셀 내부 엘리먼트의 값을 `value`로 변경하는 이벤트를 반환합니다.
+    ///
+    /// 셀 내부 엘리먼트에 따라 다음 이벤트를 반환합니다.
+    /// - [`InputField`](crate::element::text::InputField): `value`로 변경하는 `Change` 이벤트
+    /// - [`ComboBox`](crate::element::selection::ComboBox): 표시되는 텍스트가 `value`와 일치하는 선택지를 선택하는 `Select` 이벤트.
+    ///   일치하는 선택지가 없다면 `value`를 선택지의 키로 취급합니다.
+    /// - [`CheckBox`](crate::element::selection::CheckBox): `value`(`true` 혹은 `false`)로 체크 상태를 변경하는 `Toggle` 이벤트
+    ///
+    /// 셀이 읽기 전용이거나 변경할 수 없는 엘리먼트라면 오류를 반환합니다.
+    pub fn edit_cell(
+        &self,
+        row: usize,
+        col: usize,
+        value: &str,
+        parser: &'a ElementParser,
+    ) -> Result<Event, WebDynproError> {
+        if self.is_cell_readonly(row, col, parser)? {
+            return Err(ElementError::InvalidContent {
+                element: self.cell(row, col)?.id().to_string(),
+                content: format!("Cell at row {row}, column {col} is readonly"),
+            })?;
+        }
+        match self.cell_element(row, col, parser)? {
+            ElementDefWrapper::InputField(def) => parser.element_from_def(&def)?.change(value),
+            ElementDefWrapper::ComboBox(def) => {
+                let combo_box = parser.element_from_def(&def)?;
+                match combo_box.key_for_text(value, ComboBoxTextMatch::Exact, parser) {
+                    Ok(key) => combo_box.select(&key, false),
+                    // 선택지를 읽을 수 없거나 `value`가 키라면 키로 선택합니다.
+                    Err(_)
+                        if combo_box.item_infos(parser).is_err()
+                            || combo_box.text_for_key(value, parser).is_ok() =>
+                    {
+                        combo_box.select(value, false)
+                    }
+                    Err(err) => Err(err),
+                }
+            }
+            ElementDefWrapper::CheckBox(def) => {
+                let checked = value.parse::<bool>().or(Err(ElementError::InvalidContent {
+                    element: def.id().to_string(),
+                    content: format!("Value {value} is not a boolean"),
+                }))?;
+                parser.element_from_def(&def)?.toggle(checked)
+            }
+            other => Err(ElementError::InvalidContent {
+                element: other.id().to_string(),
+                content: "Editable element in table cell".to_string(),
+            })?,
+        }
+    }
+
+    /// 선택된 행들의 [`Iterator`]를 반환합니다.
+    pub fn selected_rows(&'a self) -> impl Iterator<Item = &'a SapTableRow> {
+        self.iter().filter(|row| row.is_selected())
+    }
+
+    /// 선택된 행들의 인덱스를 반환합니다.
+    pub fn selected_row_indices(&'a self) -> Vec<u32> {
+        self.selected_rows()
+            .filter_map(|row| row.row_index())
+            .collect()
+    }
+
+    /// 계층형 테이블의 각 행을 깊이와 펼침 상태와 함께 반환합니다.
+    ///
+    /// [`SapTableHierarchicalCell`](super::cell::SapTableHierarchicalCell)이 없는 행은 깊이 0, 상태 `None`으로 취급합니다.
+    pub fn hierarchy(
+        &'a self,
+        parser: &'a ElementParser,
+    ) -> Result<Vec<SapTableHierarchyRow<'a>>, WebDynproError> {
+        self.iter()
+            .map(|row| {
+                let Some(cell_def) = row.hierarchical_cell() else {
+                    return Ok(SapTableHierarchyRow {
+                        row,
+                        cell_id: None,
+                        level: 0,
+                        status: None,
+                    });
+                };
+                let cell = parser.subelement_from_def(cell_def)?;
+                let lsdata = cell.lsdata();
+                Ok(SapTableHierarchyRow {
+                    row,
+                    cell_id: Some(cell_def.id().to_owned()),
+                    level: lsdata.level().copied().unwrap_or(0),
+                    status: lsdata.status().copied(),
+                })
+            })
+            .collect()
+    }
+
     /// 테이블을 [`FromSapTable`]을 구현하는 형의 [`Vec`]으로 변환합니다.
     pub fn try_table_into<T: FromSapTable<'a>>(
         &'a self,
@@ -172,6 +302,47 @@ impl<'a> SapTableBody {
     }
 }
 
+/// 계층형 [`SapTable`](super::SapTable)의 행과 계층 정보
+#[derive(Clone, Debug)]
+pub struct SapTableHierarchyRow<'a> {
+    row: &'a SapTableRow,
+    cell_id: Option<String>,
+    level: i32,
+    status: Option<SapTableHierarchicalCellStatus>,
+}
+
+impl<'a> SapTableHierarchyRow<'a> {
+    /// 원본 행을 반환합니다.
+    pub fn row(&self) -> &'a SapTableRow {
+        self.row
+    }
+
+    /// 계층 셀의 Id를 반환합니다.
+    pub fn cell_id(&self) -> Option<&str> {
+        self.cell_id.as_deref()
+    }
+
+    /// 행의 깊이를 반환합니다.
+    pub fn level(&self) -> i32 {
+        self.level
+    }
+
+    /// 행의 펼침 상태를 반환합니다.
+    pub fn status(&self) -> Option<SapTableHierarchicalCellStatus> {
+        self.status
+    }
+
+    /// 행이 접혀 있어 펼칠 수 있다면 참을 반환합니다.
+    pub fn is_collapsed(&self) -> bool {
+        self.status.is_some_and(|status| status.is_collapsed())
+    }
+
+    /// 행이 펼쳐져 있다면 참을 반환합니다.
+    pub fn is_expanded(&self) -> bool {
+        self.status.is_some_and(|status| status.is_expanded())
+    }
+}
+
 impl Index<usize> for SapTableBody {
     type Output = SapTableRow;
 
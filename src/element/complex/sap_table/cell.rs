@@ -156,6 +156,17 @@ impl SapTableCellDefWrapper {
         }
     }
 
+    /// 셀의 id를 반환합니다.
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Normal(def) => def.id(),
+            Self::Header(def) => def.id(),
+            Self::Hierarchical(def) => def.id(),
+            Self::Matrix(def) => def.id(),
+            Self::Selection(def) => def.id(),
+        }
+    }
+
     /// [`ElementParser`]로 서브 엘리먼트를 가져옵니다.
     pub fn with_parser<'body>(
         &self,
@@ -0,0 +1,395 @@
+use std::collections::HashSet;
+
+use serde::{
+    Deserialize, Deserializer,
+    de::{
+        DeserializeOwned, IntoDeserializer, Visitor,
+        value::{MapDeserializer, SeqDeserializer},
+    },
+    forward_to_deserialize_any,
+};
+
+use super::{FromSapTable, SapTableBody, SapTableHeader, SapTableRow};
+use crate::element::parser::ElementParser;
+use crate::error::{ElementError, WebDynproError};
+
+/// [`SapTableRow`]를 헤더 제목에서 셀 텍스트로의 맵으로 표현하는 [`Deserializer`]
+///
+/// 셀 텍스트는 필드의 형에 따라 `bool`, 정수, 실수로 변환되며, 비어 있는 셀은 `Option` 필드에서 `None`이 됩니다.
+/// 맵으로 역직렬화할 때 제목이 비어 있는 열은 건너뛰며, 같은 제목을 가진 열이 여럿이라면 오류를 반환합니다.
+/// 제목이 겹치는 테이블은 시퀀스(튜플 등)로 역직렬화하여 위치로 읽을 수 있습니다.
+#[derive(Clone, Debug)]
+pub struct SapTableRowDeserializer {
+    cells: Vec<(String, Option<String>)>,
+}
+
+impl SapTableRowDeserializer {
+    /// 주어진 행과 헤더로 새로운 [`SapTableRowDeserializer`]를 생성합니다.
+    pub fn new(
+        header: Option<&SapTableHeader>,
+        row: &SapTableRow,
+        parser: &ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let cells = row.try_row_into::<Vec<(String, Option<String>)>>(header, parser)?;
+        Ok(Self { cells })
+    }
+}
+
+impl<'de> Deserializer<'de> for SapTableRowDeserializer {
+    type Error = ElementError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let mut titles = HashSet::new();
+        if let Some(title) = self
+            .cells
+            .iter()
+            .map(|(title, _)| title)
+            .find(|title| !title.is_empty() && !titles.insert(title.as_str()))
+        {
+            Err(ElementError::DeserializeTable(format!(
+                "Duplicate column title {title:?}"
+            )))?
+        }
+        let map = MapDeserializer::new(
+            self.cells
+                .into_iter()
+                .filter(|(title, _)| !title.is_empty())
+                .map(|(title, text)| (title, CellDeserializer(text))),
+        );
+        visitor.visit_map(map)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        let seq = SeqDeserializer::new(
+            self.cells
+                .into_iter()
+                .map(|(_title, text)| CellDeserializer(text)),
+        );
+        visitor.visit_seq(seq)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct map struct enum
+        identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, ElementError> for SapTableRowDeserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+/// [`SapTableBody`]의 행들을 [`SapTableRowDeserializer`]의 시퀀스로 표현하는 [`Deserializer`]
+#[derive(Clone, Debug)]
+pub struct SapTableBodyDeserializer {
+    rows: Vec<SapTableRowDeserializer>,
+}
+
+impl SapTableBodyDeserializer {
+    /// 주어진 테이블로 새로운 [`SapTableBodyDeserializer`]를 생성합니다.
+    pub fn new(body: &SapTableBody, parser: &ElementParser) -> Result<Self, WebDynproError> {
+        let rows = body
+            .iter()
+            .map(|row| SapTableRowDeserializer::new(body.header(), row, parser))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { rows })
+    }
+}
+
+impl<'de> Deserializer<'de> for SapTableBodyDeserializer {
+    type Error = ElementError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(SeqDeserializer::new(self.rows.into_iter()))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// serde로 역직렬화할 수 있는 형을 [`FromSapTable`]으로 변환할 수 있도록 감싸는 형
+///
+/// ```ignore
+/// let rows = body
+///     .try_table_into::<Deserialized<MyRow>>(&parser)?
+///     .into_iter()
+///     .map(Deserialized::into_inner)
+///     .collect::<Vec<MyRow>>();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Deserialized<T>(pub T);
+
+impl<T> Deserialized<T> {
+    /// 내부 값을 반환합니다.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<'body, T: DeserializeOwned> FromSapTable<'body> for Deserialized<T> {
+    fn from_table(
+        header: Option<&'body SapTableHeader>,
+        row: &'body SapTableRow,
+        parser: &'body ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let deserializer = SapTableRowDeserializer::new(header, row, parser)?;
+        Ok(Deserialized(T::deserialize(deserializer)?))
+    }
+}
+
+struct CellDeserializer(Option<String>);
+
+impl CellDeserializer {
+    fn parse<T: std::str::FromStr>(self, expected: &str) -> Result<T, ElementError>
+    where
+        T::Err: std::fmt::Display,
+    {
+        let text = self.0.unwrap_or_default();
+        text.trim().parse::<T>().map_err(|err| {
+            ElementError::DeserializeTable(format!("Cannot parse {text:?} as {expected}: {err}"))
+        })
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident: $ty:ty),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                visitor.$visit(self.parse::<$ty>(stringify!($ty))?)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for CellDeserializer {
+    type Error = ElementError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Some(text) => visitor.visit_string(text),
+            None => visitor.visit_none(),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match &self.0 {
+            Some(text) if !text.trim().is_empty() => visitor.visit_some(self),
+            _ => visitor.visit_none(),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_string(self.0.unwrap_or_default())
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_enum(self.0.unwrap_or_default().into_deserializer())
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool: bool,
+        deserialize_i8 => visit_i8: i8,
+        deserialize_i16 => visit_i16: i16,
+        deserialize_i32 => visit_i32: i32,
+        deserialize_i64 => visit_i64: i64,
+        deserialize_i128 => visit_i128: i128,
+        deserialize_u8 => visit_u8: u8,
+        deserialize_u16 => visit_u16: u16,
+        deserialize_u32 => visit_u32: u32,
+        deserialize_u64 => visit_u64: u64,
+        deserialize_u128 => visit_u128: u128,
+        deserialize_f32 => visit_f32: f32,
+        deserialize_f64 => visit_f64: f64,
+        deserialize_char => visit_char: char,
+    }
+
+    forward_to_deserialize_any! {
+        bytes byte_buf unit_struct seq tuple tuple_struct map struct identifier
+        ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, ElementError> for CellDeserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl SapTableRow {
+    /// 이 행을 헤더 제목에서 셀 텍스트로의 맵으로 표현하는 [`SapTableRowDeserializer`]를 반환합니다.
+    pub fn deserializer(
+        &self,
+        header: Option<&SapTableHeader>,
+        parser: &ElementParser,
+    ) -> Result<SapTableRowDeserializer, WebDynproError> {
+        SapTableRowDeserializer::new(header, self, parser)
+    }
+
+    /// 이 행을 serde로 역직렬화할 수 있는 형으로 변환합니다.
+    pub fn deserialize_into<T: DeserializeOwned>(
+        &self,
+        header: Option<&SapTableHeader>,
+        parser: &ElementParser,
+    ) -> Result<T, WebDynproError> {
+        Ok(T::deserialize(self.deserializer(header, parser)?)?)
+    }
+}
+
+impl SapTableBody {
+    /// 이 테이블을 행의 시퀀스로 표현하는 [`SapTableBodyDeserializer`]를 반환합니다.
+    pub fn deserializer(
+        &self,
+        parser: &ElementParser,
+    ) -> Result<SapTableBodyDeserializer, WebDynproError> {
+        SapTableBodyDeserializer::new(self, parser)
+    }
+
+    /// 이 테이블의 행들을 serde로 역직렬화할 수 있는 형의 [`Vec`]으로 변환합니다.
+    pub fn deserialize_into<T: DeserializeOwned>(
+        &self,
+        parser: &ElementParser,
+    ) -> Result<Vec<T>, WebDynproError> {
+        Ok(Vec::<T>::deserialize(self.deserializer(parser)?)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::SapTableRowDeserializer;
+    use crate::error::ElementError;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    enum Kind {
+        Major,
+        Elective,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Lecture {
+        #[serde(rename = "Name")]
+        name: String,
+        #[serde(rename = "Credit")]
+        credit: f32,
+        #[serde(rename = "Seats")]
+        seats: u32,
+        #[serde(rename = "Kind")]
+        kind: Kind,
+        #[serde(rename = "Online")]
+        online: bool,
+        #[serde(rename = "Note")]
+        note: Option<String>,
+    }
+
+    fn row(cells: &[(&str, Option<&str>)]) -> SapTableRowDeserializer {
+        SapTableRowDeserializer {
+            cells: cells
+                .iter()
+                .map(|(title, text)| (title.to_string(), text.map(str::to_string)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn deserialize_struct_fields() {
+        let cells = [
+            ("", Some("X")),
+            ("Name", Some("Algebra")),
+            ("Credit", Some(" 2.5 ")),
+            ("Seats", Some("40")),
+            ("Kind", Some("Major")),
+            ("Online", Some("true")),
+            ("Note", Some("  ")),
+        ];
+        assert_eq!(
+            Lecture::deserialize(row(&cells)).unwrap(),
+            Lecture {
+                name: "Algebra".to_string(),
+                credit: 2.5,
+                seats: 40,
+                kind: Kind::Major,
+                online: true,
+                note: None,
+            }
+        );
+        let mut cells = cells;
+        cells[4].1 = Some("Required");
+        assert!(Lecture::deserialize(row(&cells)).is_err());
+        cells[4].1 = Some("Elective");
+        cells[3].1 = Some("-1");
+        assert!(matches!(
+            Lecture::deserialize(row(&cells)),
+            Err(ElementError::DeserializeTable(message)) if message.contains("u32")
+        ));
+    }
+
+    #[test]
+    fn deserialize_empty_and_duplicate_titles() {
+        let map = std::collections::HashMap::<String, String>::deserialize(row(&[
+            ("", Some("X")),
+            ("", Some("Y")),
+            ("Name", Some("Algebra")),
+        ]))
+        .unwrap();
+        assert_eq!(map.len(), 1);
+        assert_eq!(map["Name"], "Algebra");
+
+        let cells = [("Name", Some("Algebra")), ("Name", Some("Physics"))];
+        assert!(matches!(
+            std::collections::HashMap::<String, String>::deserialize(row(&cells)),
+            Err(ElementError::DeserializeTable(message)) if message.contains("\"Name\"")
+        ));
+        assert_eq!(
+            <(String, String)>::deserialize(row(&cells)).unwrap(),
+            ("Algebra".to_string(), "Physics".to_string())
+        );
+    }
+}
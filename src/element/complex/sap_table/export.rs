@@ -0,0 +1,235 @@
+use std::io::Write;
+
+use derive_builder::Builder;
+use serde_json::{Map, Value};
+
+use super::{SapTableBody, SapTableHeader, SapTableRow};
+use crate::element::parser::ElementParser;
+use crate::error::{ElementError, WebDynproError};
+
+/// [`SapTableBody`]를 CSV, JSON, NDJSON으로 내보낼 때의 옵션
+#[allow(missing_docs)]
+#[derive(Builder, Clone, Debug)]
+#[builder(default)]
+pub struct SapTableExportOptions {
+    /// CSV의 첫 줄에 헤더 제목을 포함할지 여부
+    include_header: bool,
+    /// 행의 `row_index`를 포함할지 여부
+    include_row_index: bool,
+    /// 행의 `user_data`를 포함할지 여부
+    include_user_data: bool,
+    /// 행의 `row_type`을 포함할지 여부
+    include_row_type: bool,
+}
+
+impl Default for SapTableExportOptions {
+    fn default() -> Self {
+        Self {
+            include_header: true,
+            include_row_index: false,
+            include_user_data: false,
+            include_row_type: false,
+        }
+    }
+}
+
+impl SapTableExportOptions {
+    /// CSV의 첫 줄에 헤더 제목을 포함한다면 참을 반환합니다.
+    pub fn include_header(&self) -> bool {
+        self.include_header
+    }
+
+    /// 행의 `row_index`를 포함한다면 참을 반환합니다.
+    pub fn include_row_index(&self) -> bool {
+        self.include_row_index
+    }
+
+    /// 행의 `user_data`를 포함한다면 참을 반환합니다.
+    pub fn include_user_data(&self) -> bool {
+        self.include_user_data
+    }
+
+    /// 행의 `row_type`을 포함한다면 참을 반환합니다.
+    pub fn include_row_type(&self) -> bool {
+        self.include_row_type
+    }
+
+    fn metadata_titles(&self) -> Vec<&'static str> {
+        let mut titles = Vec::new();
+        if self.include_row_index {
+            titles.push("row_index");
+        }
+        if self.include_user_data {
+            titles.push("user_data");
+        }
+        if self.include_row_type {
+            titles.push("row_type");
+        }
+        titles
+    }
+
+    fn metadata_values(&self, row: &SapTableRow) -> Vec<Value> {
+        let mut values = Vec::new();
+        if self.include_row_index {
+            values.push(row.row_index().map_or(Value::Null, Value::from));
+        }
+        if self.include_user_data {
+            values.push(row.user_data().map_or(Value::Null, Value::from));
+        }
+        if self.include_row_type {
+            values.push(Value::from(format!("{:?}", row.row_type())));
+        }
+        values
+    }
+}
+
+impl SapTableBody {
+    /// 테이블을 CSV 형식으로 `writer`에 씁니다.
+    ///
+    /// 각 줄은 `\r\n`으로 끝나며, 쉼표, 따옴표, 줄바꿈을 포함하는 값은 따옴표로 감쌉니다.
+    pub fn write_csv<W: Write>(
+        &self,
+        mut writer: W,
+        parser: &ElementParser,
+        options: &SapTableExportOptions,
+    ) -> Result<(), WebDynproError> {
+        if options.include_header {
+            let mut record = options
+                .metadata_titles()
+                .into_iter()
+                .map(str::to_owned)
+                .collect::<Vec<String>>();
+            record.extend(export_titles(self.header(), parser));
+            write_csv_record(&mut writer, &record)?;
+        }
+        for row in self.iter() {
+            let mut record = options
+                .metadata_values(row)
+                .into_iter()
+                .map(|value| match value {
+                    Value::String(str) => str,
+                    Value::Null => String::new(),
+                    other => other.to_string(),
+                })
+                .collect::<Vec<String>>();
+            record.extend(
+                row_texts(row, parser)?
+                    .into_iter()
+                    .map(Option::unwrap_or_default),
+            );
+            write_csv_record(&mut writer, &record)?;
+        }
+        writer.flush().map_err(ElementError::from)?;
+        Ok(())
+    }
+
+    /// 테이블을 헤더 제목을 키로 하는 오브젝트의 JSON 배열로 `writer`에 씁니다.
+    ///
+    /// 제목이 비어 있는 열은 열의 인덱스를 키로 사용합니다.
+    /// 키가 이미 사용된 경우(겹치는 제목이나 `row_index` 등의 메타데이터 키) 뒤에 `_2`, `_3`, ...을 붙여 구분합니다.
+    pub fn write_json<W: Write>(
+        &self,
+        mut writer: W,
+        parser: &ElementParser,
+        options: &SapTableExportOptions,
+    ) -> Result<(), WebDynproError> {
+        let titles = export_titles(self.header(), parser);
+        writer.write_all(b"[").map_err(ElementError::from)?;
+        for (index, row) in self.iter().enumerate() {
+            if index > 0 {
+                writer.write_all(b",").map_err(ElementError::from)?;
+            }
+            let object = row_object(row, &titles, parser, options)?;
+            serde_json::to_writer(&mut writer, &object).map_err(ElementError::from)?;
+        }
+        writer.write_all(b"]").map_err(ElementError::from)?;
+        writer.flush().map_err(ElementError::from)?;
+        Ok(())
+    }
+
+    /// 테이블의 각 행을 헤더 제목을 키로 하는 JSON 오브젝트로 한 줄씩 `writer`에 씁니다.
+    ///
+    /// 오브젝트의 키는 [`SapTableBody::write_json()`]과 같은 방식으로 정해집니다.
+    pub fn write_ndjson<W: Write>(
+        &self,
+        mut writer: W,
+        parser: &ElementParser,
+        options: &SapTableExportOptions,
+    ) -> Result<(), WebDynproError> {
+        let titles = export_titles(self.header(), parser);
+        for row in self.iter() {
+            let object = row_object(row, &titles, parser, options)?;
+            serde_json::to_writer(&mut writer, &object).map_err(ElementError::from)?;
+            writer.write_all(b"\n").map_err(ElementError::from)?;
+        }
+        writer.flush().map_err(ElementError::from)?;
+        Ok(())
+    }
+}
+
+fn export_titles(header: Option<&SapTableHeader>, parser: &ElementParser) -> Vec<String> {
+    header
+        .map(|header| header.titles_lossy(parser))
+        .unwrap_or_default()
+}
+
+fn row_texts(
+    row: &SapTableRow,
+    parser: &ElementParser,
+) -> Result<Vec<Option<String>>, WebDynproError> {
+    row.try_row_into::<Vec<Option<String>>>(None, parser)
+}
+
+fn row_object(
+    row: &SapTableRow,
+    titles: &[String],
+    parser: &ElementParser,
+    options: &SapTableExportOptions,
+) -> Result<Map<String, Value>, WebDynproError> {
+    let mut object = options
+        .metadata_titles()
+        .into_iter()
+        .map(str::to_owned)
+        .zip(options.metadata_values(row))
+        .collect::<Map<String, Value>>();
+    for (index, text) in row_texts(row, parser)?.into_iter().enumerate() {
+        let title = titles
+            .get(index)
+            .filter(|title| !title.is_empty())
+            .cloned()
+            .unwrap_or_else(|| index.to_string());
+        let key = unique_key(&object, title);
+        object.insert(key, text.map_or(Value::Null, Value::from));
+    }
+    Ok(object)
+}
+
+// 이미 사용된 키라면 `_2`, `_3`, ...을 붙여 사용되지 않은 키를 만듭니다.
+fn unique_key(object: &Map<String, Value>, key: String) -> String {
+    if !object.contains_key(&key) {
+        return key;
+    }
+    (2..)
+        .map(|suffix| format!("{key}_{suffix}"))
+        .find(|candidate| !object.contains_key(candidate))
+        .unwrap()
+}
+
+fn write_csv_record<W: Write>(writer: &mut W, record: &[String]) -> Result<(), ElementError> {
+    let line = record
+        .iter()
+        .map(|field| escape_csv_field(field))
+        .collect::<Vec<String>>()
+        .join(",");
+    writer.write_all(line.as_bytes())?;
+    writer.write_all(b"\r\n")?;
+    Ok(())
+}
+
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+use std::ops::Index;
+
+use super::{SapTableDef, cell::SapTableCellDefWrapper};
+
+/// [`SapTableGrid`]의 논리적인 한 칸
+///
+/// `rowspan`이나 `colspan`으로 여러 칸을 차지하는 셀은 자신이 시작하는 칸(원점)과
+/// 이어지는 칸(연속 칸)에 모두 기록되며, 각 칸은 원점의 위치와 셀의 크기를 가집니다.
+#[derive(Clone, Debug)]
+pub struct SapTableGridCell {
+    def: SapTableCellDefWrapper,
+    origin_row: usize,
+    origin_col: usize,
+    row_span: u32,
+    col_span: u32,
+    continuation: bool,
+}
+
+impl SapTableGridCell {
+    /// 이 칸을 차지하는 셀의 정의를 반환합니다.
+    pub fn def(&self) -> &SapTableCellDefWrapper {
+        &self.def
+    }
+
+    /// 셀이 시작하는 행의 위치를 반환합니다.
+    pub fn origin_row(&self) -> usize {
+        self.origin_row
+    }
+
+    /// 셀이 시작하는 열의 위치를 반환합니다.
+    pub fn origin_col(&self) -> usize {
+        self.origin_col
+    }
+
+    /// 셀이 차지하는 행의 수를 반환합니다.
+    pub fn row_span(&self) -> u32 {
+        self.row_span
+    }
+
+    /// 셀이 차지하는 열의 수를 반환합니다.
+    pub fn col_span(&self) -> u32 {
+        self.col_span
+    }
+
+    /// 이 칸이 셀이 시작하는 칸이 아닌, 이어지는 칸이라면 참을 반환합니다.
+    pub fn is_continuation(&self) -> bool {
+        self.continuation
+    }
+
+    /// 이 칸이 셀이 시작하는 칸이라면 참을 반환합니다.
+    pub fn is_origin(&self) -> bool {
+        !self.continuation
+    }
+}
+
+/// 셀의 `rowspan`, `colspan` 정보를 보존하는 [`SapTable`](super::SapTable)의 2차원 격자
+///
+/// 격자의 행은 [`SapTableBody`](super::SapTableBody)의 행과 같은 순서를 가집니다.
+#[derive(Clone, Debug)]
+pub struct SapTableGrid {
+    table_def: SapTableDef,
+    rows: Vec<Vec<SapTableGridCell>>,
+}
+
+impl SapTableGrid {
+    /// 원본 [`SapTable`](super::SapTable)의 [`SapTableDef`]를 반환합니다.
+    pub fn table_def(&self) -> SapTableDef {
+        self.table_def.clone()
+    }
+
+    /// 격자의 행 수를 반환합니다.
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// 격자에서 가장 긴 행의 열 수를 반환합니다.
+    pub fn col_count(&self) -> usize {
+        self.rows.iter().map(Vec::len).max().unwrap_or(0)
+    }
+
+    /// 격자가 비어있는지 확인합니다.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// 주어진 위치의 칸을 반환합니다.
+    pub fn get(&self, row: usize, col: usize) -> Option<&SapTableGridCell> {
+        self.rows.get(row)?.get(col)
+    }
+
+    /// 주어진 행의 칸들을 반환합니다.
+    pub fn row(&self, row: usize) -> Option<&[SapTableGridCell]> {
+        self.rows.get(row).map(Vec::as_slice)
+    }
+
+    /// 격자 행의 [`Iterator`]를 반환합니다.
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[SapTableGridCell]> {
+        self.rows.iter().map(Vec::as_slice)
+    }
+
+    /// 원점 칸의 [`Iterator`]를 반환합니다. 각 셀은 한번씩만 반환됩니다.
+    pub fn origins(&self) -> impl Iterator<Item = &SapTableGridCell> {
+        self.rows.iter().flatten().filter(|cell| cell.is_origin())
+    }
+}
+
+impl Index<(usize, usize)> for SapTableGrid {
+    type Output = SapTableGridCell;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.rows[row][col]
+    }
+}
+
+/// 행 단위로 셀을 받아 `rowspan`으로 이어지는 칸을 채워가며 [`SapTableGrid`]를 만드는 빌더
+pub(super) struct SapTableGridBuilder {
+    table_def: SapTableDef,
+    rows: Vec<Vec<SapTableGridCell>>,
+    // 열 위치 -> (아래 행으로 이어지는 칸, 남은 행 수)
+    pending: BTreeMap<usize, (SapTableGridCell, u32)>,
+    current: Vec<SapTableGridCell>,
+}
+
+impl SapTableGridBuilder {
+    pub(super) fn new(table_def: SapTableDef) -> Self {
+        Self {
+            table_def,
+            rows: Vec::new(),
+            pending: BTreeMap::new(),
+            current: Vec::new(),
+        }
+    }
+
+    /// 현재 행에 셀을 추가합니다. 셀 앞에 위쪽 행에서 이어지는 칸이 있다면 먼저 채웁니다.
+    pub(super) fn push_cell(&mut self, def: SapTableCellDefWrapper, row_span: u32, col_span: u32) {
+        self.fill_pending();
+        let row_span = row_span.max(1);
+        let col_span = col_span.max(1);
+        let origin_row = self.rows.len();
+        let origin_col = self.current.len();
+        for offset in 0..col_span as usize {
+            let cell = SapTableGridCell {
+                def: def.clone(),
+                origin_row,
+                origin_col,
+                row_span,
+                col_span,
+                continuation: offset > 0,
+            };
+            if row_span > 1 {
+                let continued = SapTableGridCell {
+                    continuation: true,
+                    ..cell.clone()
+                };
+                self.pending
+                    .insert(origin_col + offset, (continued, row_span - 1));
+            }
+            self.current.push(cell);
+        }
+    }
+
+    /// 현재 행을 마치고, 행의 칸 정의들을 반환합니다.
+    pub(super) fn finish_row(&mut self) -> Vec<SapTableCellDefWrapper> {
+        self.fill_pending();
+        let row = std::mem::take(&mut self.current);
+        let defs = row.iter().map(|cell| cell.def.clone()).collect();
+        self.rows.push(row);
+        defs
+    }
+
+    pub(super) fn build(self) -> SapTableGrid {
+        SapTableGrid {
+            table_def: self.table_def,
+            rows: self.rows,
+        }
+    }
+
+    fn fill_pending(&mut self) {
+        while let Some((cell, remaining)) = self.pending.remove(&self.current.len()) {
+            if remaining > 1 {
+                self.pending
+                    .insert(self.current.len(), (cell.clone(), remaining - 1));
+            }
+            self.current.push(cell);
+        }
+    }
+}
@@ -1,15 +1,19 @@
-use std::ops::Index;
+use std::{ops::Index, sync::OnceLock};
 
 use scraper::ElementRef;
 
 use super::{
     SapTableDef,
-    cell::{SapTableCell, SapTableCellDefWrapper, SapTableCellWrapper},
-    property::{SapTableRowType, SapTableSelectionState},
+    cell::{SapTableCell, SapTableCellDefWrapper, SapTableCellWrapper, SapTableHeaderCellDef},
+    property::{
+        SapTableHeaderCellType, SapTableRowSelectionMassState, SapTableRowType,
+        SapTableSelectionState,
+    },
 };
 use crate::element::parser::ElementParser;
+use crate::element::sub::SubElement as _;
 use crate::{
-    element::{Element, ElementDefWrapper, definition::ElementDefinition},
+    element::{Element, ElementDefWrapper, ElementWrapper, definition::ElementDefinition},
     error::{ElementError, WebDynproError},
 };
 
@@ -25,6 +29,7 @@ pub struct SapTableHeader {
     drop_target_info: Option<String>,
     parent_drop_target_info: Option<String>,
     selection_state: SapTableSelectionState,
+    column_titles: OnceLock<Vec<String>>,
 }
 
 impl<'a> SapTableHeader {
@@ -49,11 +54,12 @@ impl<'a> SapTableHeader {
             table_def,
             cells,
             row_index: row.attr("rr").and_then(|s| s.parse::<u32>().ok()),
-            user_data: row.attr("uDat").map(|s| s.to_owned()),
-            drag_data: row.attr("ddData").map(|s| s.to_owned()),
-            drop_target_info: row.attr("ddDti").map(|s| s.to_owned()),
-            parent_drop_target_info: row.attr("ddPDti").map(|s| s.to_owned()),
+            user_data: row.attr("udat").map(|s| s.to_owned()),
+            drag_data: row.attr("dddata").map(|s| s.to_owned()),
+            drop_target_info: row.attr("dddti").map(|s| s.to_owned()),
+            parent_drop_target_info: row.attr("ddpdti").map(|s| s.to_owned()),
             selection_state: row.attr("sst").map(|s| s.into()).unwrap_or_default(),
+            column_titles: OnceLock::new(),
         })
     }
 
@@ -119,6 +125,85 @@ impl<'a> SapTableHeader {
             .collect::<Result<Vec<String>, WebDynproError>>()
     }
 
+    /// 헤더 행 제목들의 [`Vec`]를 반환합니다.
+    ///
+    /// [`SapTableHeader::titles`]와 달리 제목을 읽을 수 없는 헤더 셀(예: 선택 열)은 빈 문자열로 취급합니다.
+    pub fn titles_lossy(&'a self, parser: &'a ElementParser) -> Vec<String> {
+        if let Ok(titles) = self.titles(parser) {
+            return titles;
+        }
+        self.iter_value(parser)
+            .map(|cell| {
+                cell.ok()
+                    .and_then(|cell| cell.content())
+                    .and_then(|content| {
+                        ElementWrapper::from_def(&content, parser)
+                            .ok()
+                            .and_then(|elem| elem.try_into().ok())
+                    })
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// 주어진 제목을 가진 열의 인덱스를 반환합니다.
+    ///
+    /// 제목을 읽을 수 없는 헤더 셀(예: 선택 열)은 [`SapTableHeader::titles_lossy()`]와 같이 빈 제목으로 취급합니다.
+    /// 헤더의 제목은 처음 호출될 때 한번만 읽습니다.
+    pub fn column_index(&'a self, title: &str, parser: &'a ElementParser) -> Option<usize> {
+        if title.is_empty() {
+            return None;
+        }
+        self.column_titles
+            .get_or_init(|| self.titles_lossy(parser))
+            .iter()
+            .position(|column| column == title)
+    }
+
+    /// 주어진 제목을 가진 헤더 셀의 정의를 반환합니다.
+    pub fn cell_by_title(
+        &'a self,
+        title: &str,
+        parser: &'a ElementParser,
+    ) -> Result<&'a SapTableCellDefWrapper, WebDynproError> {
+        let index =
+            self.column_index(title, parser)
+                .ok_or_else(|| ElementError::NoSuchContent {
+                    element: self.table_def.id().to_string(),
+                    content: format!("Header cell with title {title}"),
+                })?;
+        Ok(&self.cells[index])
+    }
+
+    /// 선택 열의 헤더 셀 정의를 반환합니다.
+    pub fn selection_cell(&self, parser: &ElementParser) -> Option<&SapTableHeaderCellDef> {
+        self.cells.iter().find_map(|cell| {
+            let SapTableCellDefWrapper::Header(def) = cell else {
+                return None;
+            };
+            let header_cell = parser.subelement_from_def(def).ok()?;
+            matches!(
+                header_cell.lsdata().header_cell_type(),
+                Some(SapTableHeaderCellType::SelectionColumn)
+            )
+            .then_some(def)
+        })
+    }
+
+    /// 선택 열 헤더 셀이 나타내는 전체 행의 선택 상태를 반환합니다.
+    pub fn row_selection_mass_state(
+        &self,
+        parser: &ElementParser,
+    ) -> Option<SapTableRowSelectionMassState> {
+        let def = self.selection_cell(parser)?;
+        parser
+            .subelement_from_def(def)
+            .ok()?
+            .lsdata()
+            .row_selection_mass_state()
+            .copied()
+    }
+
     /// 원본 [`SapTable`](super::SapTable)의 [`ElementDefinition`]를 반환합니다.
     pub fn table_def(&self) -> SapTableDef {
         self.table_def.clone()
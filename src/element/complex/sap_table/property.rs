@@ -3,6 +3,7 @@ use std::fmt::Display;
 
 /// 테이블 내의 셀 접근 방식
 #[allow(missing_docs)]
+#[derive(Clone, Debug)]
 pub enum AccessType {
     Invalid,
     Standard,
@@ -311,7 +312,7 @@ pub enum SapTableHeaderCellType {
 
 /// [`SapTableHierarchicalCell`](super::cell::SapTableHierarchicalCell)의 상태
 #[allow(missing_docs)]
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Copy, Deserialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum SapTableHierarchicalCellStatus {
     None,
@@ -326,9 +327,24 @@ pub enum SapTableHierarchicalCellStatus {
     Icon,
 }
 
+impl SapTableHierarchicalCellStatus {
+    /// 접혀 있어 펼칠 수 있는 상태라면 참을 반환합니다.
+    pub fn is_collapsed(&self) -> bool {
+        matches!(self, Self::Collapsed | Self::Collapsedplus)
+    }
+
+    /// 펼쳐져 있어 접을 수 있는 상태라면 참을 반환합니다.
+    pub fn is_expanded(&self) -> bool {
+        matches!(
+            self,
+            Self::Expanded | Self::Expandedminus | Self::Expandedtop
+        )
+    }
+}
+
 /// 테이블의 선택 상태
 #[allow(missing_docs)]
-#[derive(Deserialize, Debug, Default, Clone, Copy)]
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SapTableSelectionState {
     NotSelectable,
@@ -339,9 +355,16 @@ pub enum SapTableSelectionState {
     None,
 }
 
+impl SapTableSelectionState {
+    /// 선택된 상태라면 참을 반환합니다.
+    pub fn is_selected(&self) -> bool {
+        matches!(self, Self::Selected | Self::PrimarySelected)
+    }
+}
+
 /// 테이블 내부 행의 전체적인 선택 상태
 #[allow(missing_docs)]
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Copy, Deserialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum SapTableRowSelectionMassState {
     None,
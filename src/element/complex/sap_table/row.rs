@@ -4,11 +4,14 @@ use scraper::ElementRef;
 
 use super::{
     FromSapTable, SapTableDef, SapTableHeader,
-    cell::{SapTableCellDefWrapper, SapTableCellWrapper},
+    cell::{
+        SapTableCellDefWrapper, SapTableCellWrapper, SapTableHierarchicalCellDef,
+        SapTableSelectionCellDef,
+    },
     property::{SapTableRowType, SapTableSelectionState},
 };
 use crate::element::parser::ElementParser;
-use crate::error::{ElementError, WebDynproError};
+use crate::error::WebDynproError;
 
 /// [`SapTable`](super::SapTable)의 행
 #[derive(Clone, Debug)]
@@ -30,19 +33,19 @@ impl<'a> SapTableRow {
         table_def: SapTableDef,
         row_ref: ElementRef<'a>,
         cells: Vec<SapTableCellDefWrapper>,
-    ) -> Result<SapTableRow, ElementError> {
+    ) -> SapTableRow {
         let row = row_ref.value();
-        Ok(SapTableRow {
+        SapTableRow {
             table_def,
             cells,
             row_index: row.attr("rr").and_then(|s| s.parse::<u32>().ok()),
-            user_data: row.attr("uDat").map(|s| s.to_owned()),
-            drag_data: row.attr("ddData").map(|s| s.to_owned()),
-            drop_target_info: row.attr("ddDti").map(|s| s.to_owned()),
-            parent_drop_target_info: row.attr("ddPDti").map(|s| s.to_owned()),
+            user_data: row.attr("udat").map(|s| s.to_owned()),
+            drag_data: row.attr("dddata").map(|s| s.to_owned()),
+            drop_target_info: row.attr("dddti").map(|s| s.to_owned()),
+            parent_drop_target_info: row.attr("ddpdti").map(|s| s.to_owned()),
             selection_state: row.attr("sst").map(|s| s.into()).unwrap_or_default(),
             row_type: row.attr("rt").map(|s| s.into()).unwrap_or_default(),
-        })
+        }
     }
 
     /// 행 내부 셀의 개수를 반환합니다.
@@ -106,11 +109,32 @@ impl<'a> SapTableRow {
         self.selection_state
     }
 
+    /// 행이 선택되었는지 여부를 반환합니다.
+    pub fn is_selected(&self) -> bool {
+        self.selection_state.is_selected()
+    }
+
     /// 행 종류를 반환합니다.
     pub fn row_type(&self) -> SapTableRowType {
         self.row_type
     }
 
+    /// 행 내부의 [`SapTableSelectionCell`](super::cell::SapTableSelectionCell) 정의를 반환합니다.
+    pub fn selection_cell(&self) -> Option<&SapTableSelectionCellDef> {
+        self.cells.iter().find_map(|cell| match cell {
+            SapTableCellDefWrapper::Selection(def) => Some(def),
+            _ => None,
+        })
+    }
+
+    /// 행 내부의 첫번째 [`SapTableHierarchicalCell`](super::cell::SapTableHierarchicalCell) 정의를 반환합니다.
+    pub fn hierarchical_cell(&self) -> Option<&SapTableHierarchicalCellDef> {
+        self.cells.iter().find_map(|cell| match cell {
+            SapTableCellDefWrapper::Hierarchical(def) => Some(def),
+            _ => None,
+        })
+    }
+
     /// 행을 [`FromSapTable`]을 구현하는 형으로 변환합니다.
     pub fn try_row_into<T: FromSapTable<'a>>(
         &'a self,
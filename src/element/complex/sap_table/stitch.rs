@@ -0,0 +1,144 @@
+use indexmap::IndexMap;
+
+use super::SapTableBody;
+use crate::element::definition::ElementDefinition as _;
+use crate::element::parser::ElementParser;
+use crate::error::{ElementError, WebDynproError};
+
+type StitchedRow = (Option<u32>, IndexMap<usize, Option<String>>);
+
+/// 가로로 스크롤된 [`SapTable`](super::SapTable)의 열 구간들을 이어붙여 전체 폭의 테이블을 만드는 구조체
+///
+/// 각 열은 헤더 셀의 Id로 구분되며, 여러 구간에 반복해서 나타나는 열은 한번만 기록됩니다.
+/// 첫 구간의 왼쪽부터 이어지는 열 중 이후의 모든 구간에도 나타난 열은 스크롤되지 않는 고정 열로 취급합니다.
+#[derive(Clone, Debug, Default)]
+pub struct SapTableColumnStitcher {
+    // 헤더 셀 Id -> 제목
+    columns: IndexMap<String, String>,
+    // 첫 구간의 헤더 셀 Id
+    first_keys: Vec<String>,
+    // 첫 구간의 왼쪽부터 이후 모든 구간에 나타난 열의 수
+    fixed_count: usize,
+    // 행 키 -> (행 인덱스, 열 위치 -> 셀 텍스트)
+    rows: IndexMap<String, StitchedRow>,
+    windows: usize,
+}
+
+impl SapTableColumnStitcher {
+    /// 새로운 [`SapTableColumnStitcher`]를 생성합니다.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 현재 렌더링된 열 구간을 추가하고, 이번 구간에서 새로 발견된 열의 수를 반환합니다.
+    ///
+    /// 행은 `row_index`로, `row_index`가 없는 행은 테이블 내 위치로 구분합니다.
+    pub fn add_window(
+        &mut self,
+        body: &SapTableBody,
+        parser: &ElementParser,
+    ) -> Result<usize, WebDynproError> {
+        let header = body.header().ok_or_else(|| ElementError::NoSuchContent {
+            element: body.table_def().id().to_string(),
+            content: "Header of table".to_string(),
+        })?;
+        let keys = header
+            .iter()
+            .map(|cell| cell.id().to_string())
+            .collect::<Vec<String>>();
+        let titles = header.titles_lossy(parser);
+        let mut new_columns = 0;
+        let positions = keys
+            .iter()
+            .enumerate()
+            .map(|(index, key)| {
+                if let Some(position) = self.columns.get_index_of(key) {
+                    return position;
+                }
+                new_columns += 1;
+                let title = titles.get(index).cloned().unwrap_or_default();
+                self.columns.insert_full(key.clone(), title).0
+            })
+            .collect::<Vec<usize>>();
+        if self.windows == 0 {
+            self.first_keys = keys.clone();
+            self.fixed_count = keys.len();
+        } else {
+            self.fixed_count = self
+                .first_keys
+                .iter()
+                .take(self.fixed_count)
+                .take_while(|key| keys.contains(key))
+                .count();
+        }
+        for (row_position, row) in body.iter().enumerate() {
+            let texts = row.try_row_into::<Vec<Option<String>>>(None, parser)?;
+            let row_key = row
+                .row_index()
+                .map_or_else(|| format!("#{row_position}"), |index| index.to_string());
+            let (_, cells) = self
+                .rows
+                .entry(row_key)
+                .or_insert_with(|| (row.row_index(), IndexMap::new()));
+            for (index, text) in texts.into_iter().enumerate() {
+                if let Some(&position) = positions.get(index) {
+                    cells.insert(position, text);
+                }
+            }
+        }
+        self.windows += 1;
+        Ok(new_columns)
+    }
+
+    /// 지금까지 추가된 구간의 수를 반환합니다.
+    pub fn window_count(&self) -> usize {
+        self.windows
+    }
+
+    /// 이어붙인 전체 열의 수를 반환합니다.
+    pub fn column_count(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// 이어붙인 전체 열의 제목을 반환합니다.
+    pub fn titles(&self) -> Vec<&str> {
+        self.columns.values().map(String::as_str).collect()
+    }
+
+    /// 고정 열의 위치를 반환합니다.
+    ///
+    /// 두 개 이상의 구간이 추가되기 전에는 고정 열을 알 수 없으므로 빈 [`Vec`]을 반환합니다.
+    pub fn fixed_columns(&self) -> Vec<usize> {
+        if self.windows < 2 {
+            return Vec::new();
+        }
+        self.first_keys
+            .iter()
+            .take(self.fixed_count)
+            .filter_map(|key| self.columns.get_index_of(key))
+            .collect()
+    }
+
+    /// 주어진 위치의 열이 고정 열이라면 참을 반환합니다.
+    pub fn is_fixed_column(&self, col: usize) -> bool {
+        self.fixed_columns().contains(&col)
+    }
+
+    /// 스크롤되는 열의 수를 반환합니다.
+    pub fn scrollable_column_count(&self) -> usize {
+        self.column_count() - self.fixed_columns().len()
+    }
+
+    /// 이어붙인 행들을 `(row_index, 셀 텍스트)`의 형태로 반환합니다. 셀 텍스트는 [`SapTableColumnStitcher::titles`]와 같은 순서를 가집니다.
+    pub fn rows(&self) -> Vec<(Option<u32>, Vec<Option<String>>)> {
+        self.rows
+            .values()
+            .map(|(row_index, cells)| {
+                let texts = (0..self.columns.len())
+                    .map(|position| cells.get(&position).cloned().flatten())
+                    .collect();
+                (*row_index, texts)
+            })
+            .collect()
+    }
+}
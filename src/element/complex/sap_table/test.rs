@@ -0,0 +1,420 @@
+use scraper::{Html, Selector};
+
+use super::{
+    SapTableBody, SapTableColumnStitcher, SapTableDef, SapTableExportOptionsBuilder, SapTableGrid,
+};
+use crate::body::test::body;
+use crate::command::WebDynproCommandExecutor as _;
+use crate::command::element::complex::SapTableSortEventCommand;
+use crate::element::definition::ElementDefinition as _;
+use crate::element::parser::ElementParser;
+use crate::element::property::SortState;
+use crate::error::{ElementError, WebDynproError};
+
+fn cell(id: &str) -> String {
+    format!(r#"<td subct="STC" id="{id}"></td>"#)
+}
+
+fn span_cell(id: &str, rowspan: u32, colspan: u32) -> String {
+    format!(r#"<td subct="STC" id="{id}" rowspan="{rowspan}" colspan="{colspan}"></td>"#)
+}
+
+fn header(titles: &[&str]) -> String {
+    let cells = titles
+        .iter()
+        .map(|title| format!(r#"<th subct="HC" id="{title}"></th>"#))
+        .collect::<String>();
+    format!(r#"<tr rt="2">{cells}</tr>"#)
+}
+
+fn parse_body(rows: &[String]) -> SapTableBody {
+    let html = format!(
+        r#"<table><tbody id="ST-contentTBody">{}</tbody></table>"#,
+        rows.concat()
+    );
+    let document = Html::parse_fragment(&html);
+    let selector = Selector::parse(r#"[id="ST-contentTBody"]"#).unwrap();
+    let tbody = document.select(&selector).next().unwrap();
+    SapTableBody::new(SapTableDef::new_dynamic("ST".to_string()), tbody).unwrap()
+}
+
+fn row(index: u32, cells: &[String]) -> String {
+    format!(r#"<tr rt="1" rr="{index}">{}</tr>"#, cells.concat())
+}
+
+fn text_cell(id: &str, text: &str) -> String {
+    format!(
+        r#"<td subct="STC" id="{id}"><span ct="TV" id="{id}-tv" lsdata="{{}}">{text}</span></td>"#
+    )
+}
+
+fn caption_header(titles: &[&str]) -> String {
+    let ids = (0..titles.len())
+        .map(|index| format!("H{index}"))
+        .collect::<Vec<String>>();
+    let cells = ids
+        .iter()
+        .map(String::as_str)
+        .zip(titles.iter().copied())
+        .collect::<Vec<(&str, &str)>>();
+    caption_header_with_ids(&cells)
+}
+
+fn caption_header_with_ids(cells: &[(&str, &str)]) -> String {
+    let cells = cells
+        .iter()
+        .map(|(id, title)| {
+            format!(
+                r#"<th subct="HC" id="{id}"><div id="{id}-CONTENT"><span ct="CP" id="{id}-cp" lsdata="{{1:'{title}'}}"></span></div></th>"#
+            )
+        })
+        .collect::<String>();
+    format!(r#"<tr rt="2">{cells}</tr>"#)
+}
+
+/// 주어진 행들을 가진 [`SapTable`](super::SapTable)을 파싱하여 본문을 전달합니다.
+pub(crate) fn with_table<R>(
+    rows: &[String],
+    f: impl FnOnce(&ElementParser, &SapTableBody) -> R,
+) -> R {
+    let body = body(&format!(
+        r#"<div ct="ST" id="ST" lsdata="{{}}"><table><tbody id="ST-contentTBody">{}</tbody></table></div>"#,
+        rows.concat()
+    ));
+    let parser = ElementParser::new(&body);
+    let table = parser
+        .element_from_def(&SapTableDef::new_dynamic("ST".to_string()))
+        .unwrap();
+    f(&parser, table.table().unwrap())
+}
+
+fn grid_ids(grid: &SapTableGrid) -> Vec<Vec<String>> {
+    grid.rows()
+        .map(|row| row.iter().map(|cell| cell.def().id().to_string()).collect())
+        .collect()
+}
+
+fn body_ids(body: &SapTableBody) -> Vec<Vec<String>> {
+    body.iter()
+        .map(|row| row.iter().map(|cell| cell.id().to_string()).collect())
+        .collect()
+}
+
+#[test]
+fn grid_without_spans() {
+    let body = parse_body(&[
+        header(&["H1", "H2"]),
+        row(1, &[cell("A"), cell("B")]),
+        row(2, &[cell("C"), cell("D")]),
+    ]);
+    let grid = body.grid();
+    assert_eq!(grid.row_count(), 2);
+    assert_eq!(grid.col_count(), 2);
+    assert_eq!(grid_ids(grid), vec![vec!["A", "B"], vec!["C", "D"]]);
+    assert!(grid.rows().flatten().all(|cell| cell.is_origin()));
+    assert_eq!(body_ids(&body), grid_ids(grid));
+}
+
+#[test]
+fn grid_stops_at_empty_row() {
+    let body = parse_body(&[
+        row(1, &[cell("A"), cell("B")]),
+        row(0, &[cell("C"), cell("D")]),
+        row(2, &[cell("E"), cell("F")]),
+    ]);
+    assert_eq!(grid_ids(body.grid()), vec![vec!["A", "B"]]);
+}
+
+#[test]
+fn grid_timetable() {
+    // | 교시 | 월           | 화 | 수           |
+    // | 1    | A (2행)      | B  | C (3행)      |
+    // | 2    |              | D  |              |
+    // | 3    | E            | F  |              |
+    // | 4    | G (3열)                         |
+    let body = parse_body(&[
+        header(&["교시", "월", "화", "수"]),
+        row(
+            1,
+            &[
+                cell("T1"),
+                span_cell("A", 2, 1),
+                cell("B"),
+                span_cell("C", 3, 1),
+            ],
+        ),
+        row(2, &[cell("T2"), cell("D")]),
+        row(3, &[cell("T3"), cell("E"), cell("F")]),
+        row(4, &[cell("T4"), span_cell("G", 1, 3)]),
+    ]);
+    let grid = body.grid();
+    assert_eq!(
+        grid_ids(grid),
+        vec![
+            vec!["T1", "A", "B", "C"],
+            vec!["T2", "A", "D", "C"],
+            vec!["T3", "E", "F", "C"],
+            vec!["T4", "G", "G", "G"],
+        ]
+    );
+    assert_eq!(body_ids(&body), grid_ids(grid));
+
+    let a = &grid[(1, 1)];
+    assert!(a.is_continuation());
+    assert_eq!((a.origin_row(), a.origin_col()), (0, 1));
+    assert_eq!((a.row_span(), a.col_span()), (2, 1));
+
+    let c = &grid[(2, 3)];
+    assert!(c.is_continuation());
+    assert_eq!((c.origin_row(), c.origin_col()), (0, 3));
+    assert_eq!(c.row_span(), 3);
+
+    assert!(grid[(3, 1)].is_origin());
+    assert!(grid[(3, 2)].is_continuation());
+    assert_eq!(grid[(3, 3)].origin_col(), 1);
+    assert_eq!(grid[(3, 3)].col_span(), 3);
+
+    let origins = grid
+        .origins()
+        .map(|cell| cell.def().id())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        origins,
+        vec!["T1", "A", "B", "C", "T2", "D", "T3", "E", "F", "T4", "G"]
+    );
+}
+
+#[test]
+fn grid_adjacent_rowspans() {
+    // 서로 이웃한 여러 셀이 동시에 아래 행으로 이어지는 경우
+    let body = parse_body(&[
+        row(
+            1,
+            &[
+                cell("X"),
+                span_cell("P", 2, 1),
+                span_cell("Q", 2, 1),
+                cell("R"),
+            ],
+        ),
+        row(2, &[cell("Y"), cell("S")]),
+    ]);
+    assert_eq!(
+        grid_ids(body.grid()),
+        vec![vec!["X", "P", "Q", "R"], vec!["Y", "P", "Q", "S"]]
+    );
+    assert_eq!(body_ids(&body), grid_ids(body.grid()));
+}
+
+#[test]
+fn grid_trailing_rowspans() {
+    // 행의 마지막 셀들이 아래 행으로 이어지는 경우
+    let body = parse_body(&[
+        row(1, &[cell("X"), span_cell("P", 3, 1), span_cell("Q", 2, 1)]),
+        row(2, &[cell("Y")]),
+        row(3, &[cell("Z"), cell("W")]),
+    ]);
+    assert_eq!(
+        grid_ids(body.grid()),
+        vec![
+            vec!["X", "P", "Q"],
+            vec!["Y", "P", "Q"],
+            vec!["Z", "P", "W"],
+        ]
+    );
+}
+
+#[test]
+fn grid_block_span() {
+    // 여러 행과 열을 동시에 차지하는 셀
+    let body = parse_body(&[
+        row(1, &[span_cell("M", 2, 2), cell("A")]),
+        row(2, &[cell("B")]),
+        row(3, &[cell("C"), cell("D"), cell("E")]),
+    ]);
+    let grid = body.grid();
+    assert_eq!(
+        grid_ids(grid),
+        vec![
+            vec!["M", "M", "A"],
+            vec!["M", "M", "B"],
+            vec!["C", "D", "E"],
+        ]
+    );
+    assert!(grid[(0, 0)].is_origin());
+    assert!(grid[(0, 1)].is_continuation());
+    assert!(grid[(1, 0)].is_continuation());
+    assert!(grid[(1, 1)].is_continuation());
+    assert_eq!(
+        (grid[(1, 1)].origin_row(), grid[(1, 1)].origin_col()),
+        (0, 0)
+    );
+    assert_eq!((grid[(1, 1)].row_span(), grid[(1, 1)].col_span()), (2, 2));
+}
+
+fn export_rows() -> Vec<String> {
+    vec![
+        caption_header(&["Name", "Note", "Name", ""]),
+        row(
+            1,
+            &[
+                text_cell("A0", "Kim, Minsu"),
+                text_cell("A1", r#"say "hi""#),
+                text_cell("A2", "line1\nline2"),
+                text_cell("A3", "plain"),
+            ],
+        ),
+    ]
+}
+
+#[test]
+fn export_csv_escapes_fields() {
+    let csv = with_table(&export_rows(), |parser, body| {
+        let mut out = Vec::new();
+        body.write_csv(&mut out, parser, &Default::default())
+            .unwrap();
+        String::from_utf8(out).unwrap()
+    });
+    assert_eq!(
+        csv,
+        "Name,Note,Name,\r\n\"Kim, Minsu\",\"say \"\"hi\"\"\",\"line1\nline2\",plain\r\n"
+    );
+}
+
+#[test]
+fn export_json_keys_are_unique() {
+    let options = SapTableExportOptionsBuilder::default()
+        .include_row_index(true)
+        .build()
+        .unwrap();
+    let mut rows = export_rows();
+    rows[0] = caption_header(&["Name", "row_index", "Name", ""]);
+    let (json, ndjson) = with_table(&rows, |parser, body| {
+        let mut json = Vec::new();
+        body.write_json(&mut json, parser, &options).unwrap();
+        let mut ndjson = Vec::new();
+        body.write_ndjson(&mut ndjson, parser, &options).unwrap();
+        (
+            String::from_utf8(json).unwrap(),
+            String::from_utf8(ndjson).unwrap(),
+        )
+    });
+    let object = r#"{"row_index":1,"Name":"Kim, Minsu","row_index_2":"say \"hi\"","Name_2":"line1\nline2","3":"plain"}"#;
+    assert_eq!(json, format!("[{object}]"));
+    assert_eq!(ndjson, format!("{object}\n"));
+}
+
+// 주어진 헤더 셀 Id(제목과 같음)의 열들만 렌더링된 가로 스크롤 구간
+fn stitch_window(columns: &[&str], rows: &[u32]) -> Vec<String> {
+    let header = columns
+        .iter()
+        .map(|column| (*column, *column))
+        .collect::<Vec<(&str, &str)>>();
+    let mut window = vec![caption_header_with_ids(&header)];
+    window.extend(rows.iter().map(|index| {
+        let cells = columns
+            .iter()
+            .map(|column| text_cell(&format!("{column}{index}"), &format!("{column}{index}")))
+            .collect::<Vec<String>>();
+        row(*index, &cells)
+    }));
+    window
+}
+
+fn add_window(stitcher: &mut SapTableColumnStitcher, columns: &[&str], rows: &[u32]) -> usize {
+    with_table(&stitch_window(columns, rows), |parser, body| {
+        stitcher.add_window(body, parser).unwrap()
+    })
+}
+
+#[test]
+fn stitch_overlapping_windows() {
+    let mut stitcher = SapTableColumnStitcher::new();
+    assert_eq!(add_window(&mut stitcher, &["K", "A", "B"], &[1, 2]), 3);
+    assert_eq!(add_window(&mut stitcher, &["K", "B", "C"], &[1, 2]), 1);
+    assert_eq!(add_window(&mut stitcher, &["K", "C", "D"], &[1, 2, 3]), 1);
+    assert_eq!(add_window(&mut stitcher, &["K", "C", "D"], &[1, 2, 3]), 0);
+
+    assert_eq!(stitcher.window_count(), 4);
+    assert_eq!(stitcher.titles(), vec!["K", "A", "B", "C", "D"]);
+    assert_eq!(stitcher.fixed_columns(), vec![0]);
+    assert_eq!(stitcher.scrollable_column_count(), 4);
+    let text = |value: &str| Some(value.to_string());
+    assert_eq!(
+        stitcher.rows(),
+        vec![
+            (
+                Some(1),
+                vec![text("K1"), text("A1"), text("B1"), text("C1"), text("D1")]
+            ),
+            (
+                Some(2),
+                vec![text("K2"), text("A2"), text("B2"), text("C2"), text("D2")]
+            ),
+            (
+                Some(3),
+                vec![text("K3"), None, None, text("C3"), text("D3")]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn stitch_fixed_columns_need_two_windows() {
+    let mut stitcher = SapTableColumnStitcher::new();
+    add_window(&mut stitcher, &["K", "L", "A"], &[1]);
+    assert!(stitcher.fixed_columns().is_empty());
+    // 두번째 구간에 L이 없으므로 K만 고정 열입니다.
+    add_window(&mut stitcher, &["K", "B", "C"], &[1]);
+    assert_eq!(stitcher.fixed_columns(), vec![0]);
+    assert!(stitcher.is_fixed_column(0));
+    assert!(!stitcher.is_fixed_column(1));
+    assert_eq!(stitcher.scrollable_column_count(), 4);
+}
+
+#[test]
+fn sort_disabled_column_is_invalid_content() {
+    let body = body(
+        r#"<div ct="ST" id="ST" lsdata="{}" lsevents="{'Sort':[{},{}]}"><table><tbody id="ST-contentTBody"><tr rt="2"><th subct="HC" id="H0" lsdata="{0:'DISABLED'}"><div id="H0-CONTENT"><span ct="CP" id="H0-cp" lsdata="{1:'Name'}"></span></div></th></tr></tbody></table></div>"#,
+    );
+    let parser = ElementParser::new(&body);
+    let command = SapTableSortEventCommand::new(
+        SapTableDef::new_dynamic("ST".to_string()),
+        "Name",
+        SortState::Ascending,
+    );
+    let Err(WebDynproError::Element(err)) = parser.read(command) else {
+        panic!("expected an element error");
+    };
+    assert!(
+        matches!(*err, ElementError::InvalidContent { ref element, .. } if element == "H0"),
+        "expected InvalidContent, got {err:?}"
+    );
+}
+
+#[test]
+fn edit_combo_box_cell_by_text_or_key() {
+    let body = body(
+        r#"<div ct="ST" id="ST" lsdata="{}"><table><tbody id="ST-contentTBody"><tr rt="1" rr="1"><td subct="STC" id="C1"><span ct="CB" id="CB1" lsdata="{3:'LB'}" lsevents="{'Select':[{},{}]}"></span></td></tr></tbody></table></div><div ct="LIB_P" id="LB" lsdata="{}"><div ct="LIB_I" id="LB-1" data-itemkey="A" data-itemvalue1="Apple"></div><div ct="LIB_I" id="LB-2" data-itemkey="C" data-itemvalue1="Carrot"></div></div>"#,
+    );
+    let parser = ElementParser::new(&body);
+    let table = parser
+        .element_from_def(&SapTableDef::new_dynamic("ST".to_string()))
+        .unwrap();
+    let key = |value: &str| {
+        table
+            .table()
+            .unwrap()
+            .edit_cell(0, 0, value, &parser)
+            .map(|event| event.parameters()["Key"].clone())
+    };
+    assert_eq!(key("Carrot").unwrap(), "C");
+    assert_eq!(key("A").unwrap(), "A");
+    assert!(key("Zucchini").is_err());
+}
+
+#[test]
+fn table_body_is_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SapTableBody>();
+}
@@ -1,6 +1,15 @@
 use std::{borrow::Cow, cell::OnceCell};
 
+use scraper::Selector;
+
+use crate::element::Element;
+use crate::element::action::{Button, ButtonDef};
+use crate::element::definition::ElementDefinition as _;
+use crate::element::layout::ButtonRow;
 use crate::element::property::{Mode, QuickViewDesign};
+use crate::element::text::{Caption, TextView};
+use crate::error::{ElementError, WebDynproError};
+use crate::event::Event;
 use crate::{WdElement, WdLsData, wd_event};
 
 // TODO: Implement additional events and data
@@ -70,4 +79,177 @@ impl<'a> PopupWindow<'a> {
     /// 도움 버튼을 누르는 이벤트를 반환합니다.
     #[wd_event(name = "Help")]
     pub fn help(&self) {}
+
+    /// 창이 모달 창인지 여부를 반환합니다. 모드 정보가 없다면 모달 창으로 취급합니다.
+    pub fn is_modal(&self) -> bool {
+        matches!(self.lsdata().mode(), Some(Mode::Modal) | None)
+    }
+
+    /// 창의 제목을 반환합니다.
+    ///
+    /// 창의 `aria-label` 속성을 우선하며, 없다면 창 내부의 첫번째 [`Caption`]의 텍스트를 반환합니다.
+    pub fn title(&self) -> Option<String> {
+        if let Some(label) = self
+            .element_ref
+            .value()
+            .attr("aria-label")
+            .filter(|label| !label.trim().is_empty())
+        {
+            return Some(label.trim().to_string());
+        }
+        self.descendants::<Caption<'a>>()
+            .into_iter()
+            .map(|caption| caption.text().trim().to_string())
+            .find(|text| !text.is_empty())
+    }
+
+    /// 창 내부 [`TextView`]의 텍스트를 줄바꿈으로 이어붙인 메시지를 반환합니다.
+    pub fn message(&self) -> String {
+        self.descendants::<TextView<'a>>()
+            .into_iter()
+            .map(|text_view| text_view.text().trim().to_string())
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    /// 창 내부 [`ButtonRow`]들의 [`Button`]을 반환합니다. [`ButtonRow`]가 없다면 창 내부의 모든 [`Button`]을 반환합니다.
+    pub fn buttons(&self) -> Vec<Button<'a>> {
+        let rows = self.descendants::<ButtonRow<'a>>();
+        if rows.is_empty() {
+            return self.descendants::<Button<'a>>();
+        }
+        let Ok(selector) = Selector::parse(&format!(r#"[ct="{}"]"#, Button::CONTROL_ID)) else {
+            return vec![];
+        };
+        rows.iter()
+            .flat_map(|row| row.element_ref().select(&selector))
+            .filter_map(|eref| {
+                let def = ButtonDef::from_ref(eref).ok()?;
+                Button::from_ref(&def, eref).ok()
+            })
+            .collect()
+    }
+
+    /// 창의 기본 버튼 Id를 반환합니다.
+    pub fn default_button_id(&self) -> Option<&str> {
+        self.lsdata().default_button_id().map(String::as_str)
+    }
+
+    /// 창의 기본 버튼을 반환합니다.
+    pub fn default_button(&self) -> Option<Button<'a>> {
+        let id = self.default_button_id()?;
+        self.descendants::<Button<'a>>()
+            .into_iter()
+            .find(|button| button.id() == id)
+    }
+
+    /// 주어진 텍스트를 가진 버튼을 반환합니다.
+    ///
+    /// 앞뒤 공백을 무시하고 정확히 일치하는 버튼을 먼저 찾고, 없다면 대소문자를 구분하지 않고 찾습니다.
+    /// 일치하는 버튼이 없다면 버튼 텍스트의 목록을 담은 [`ElementError::NoSuchOption`] 오류를 반환합니다.
+    pub fn button_by_text(&self, text: &str) -> Result<Button<'a>, WebDynproError> {
+        let text = text.trim();
+        let buttons = self.buttons();
+        let position = buttons
+            .iter()
+            .position(|button| button.text() == text)
+            .or_else(|| {
+                buttons
+                    .iter()
+                    .position(|button| button.text().to_lowercase() == text.to_lowercase())
+            });
+        match position {
+            Some(position) => Ok(buttons.into_iter().nth(position).unwrap()),
+            None => Err(ElementError::NoSuchOption {
+                element: self.id().to_string(),
+                text: text.to_string(),
+                available: buttons.iter().map(Button::text).collect(),
+            })?,
+        }
+    }
+
+    /// 주어진 텍스트를 가진 버튼을 눌러 창에 응답하는 이벤트를 반환합니다.
+    ///
+    /// 버튼이 비활성화되어 있다면 [`ElementError::InvalidContent`] 오류를 반환합니다.
+    pub fn press_button(&self, text: &str) -> Result<Event, WebDynproError> {
+        let button = self.button_by_text(text)?;
+        if !button.enabled() {
+            return Err(ElementError::InvalidContent {
+                element: button.id().to_string(),
+                content: "enabled Button".to_string(),
+            })?;
+        }
+        button.press()
+    }
+
+    /// 창의 기본 버튼을 누르는 이벤트를 반환합니다.
+    pub fn press_default_button(&self) -> Result<Event, WebDynproError> {
+        self.default_button()
+            .ok_or_else(|| ElementError::NoSuchData {
+                element: self.id().to_string(),
+                field: "default_button_id".to_string(),
+            })?
+            .press()
+    }
+
+    fn descendants<E: Element<'a>>(&self) -> Vec<E> {
+        let Ok(selector) = Selector::parse(&format!(r#"[ct="{}"]"#, E::CONTROL_ID)) else {
+            return vec![];
+        };
+        self.element_ref
+            .select(&selector)
+            .filter_map(|eref| {
+                let def = E::Def::from_ref(eref).ok()?;
+                E::from_ref(&def, eref).ok()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PopupWindowDef;
+    use crate::body::test::body;
+    use crate::element::Element as _;
+    use crate::element::parser::ElementParser;
+    use crate::error::{ElementError, WebDynproError};
+
+    const POPUP: &str = r#"<div ct="PW" id="PW" lsdata="{}"><div ct="BR" id="BR" lsdata="{}"><div ct="B" id="B1" lsdata="{0:'Yes'}" lsevents="{'Press':[{},{}]}"></div><div ct="B" id="B2" lsdata="{0:'yes'}"></div><div ct="B" id="B3" lsdata="{}"> No </div><div ct="B" id="B4" lsdata="{0:'Later',5:false}" lsevents="{'Press':[{},{}]}"></div></div></div>"#;
+
+    #[test]
+    fn button_by_text() {
+        let body = body(POPUP);
+        let parser = ElementParser::new(&body);
+        let popup = parser.element_from_def(&PopupWindowDef::new("PW")).unwrap();
+        assert_eq!(popup.button_by_text("yes").unwrap().id(), "B2");
+        assert_eq!(popup.button_by_text(" Yes ").unwrap().id(), "B1");
+        assert_eq!(popup.button_by_text("NO").unwrap().id(), "B3");
+        let Err(WebDynproError::Element(err)) = popup.button_by_text("Cancel") else {
+            panic!("expected an element error");
+        };
+        let ElementError::NoSuchOption {
+            element, available, ..
+        } = *err
+        else {
+            panic!("expected NoSuchOption, got {err:?}");
+        };
+        assert_eq!(element, "PW");
+        assert_eq!(available, vec!["Yes", "yes", "No", "Later"]);
+    }
+
+    #[test]
+    fn press_button_rejects_disabled_button() {
+        let body = body(POPUP);
+        let parser = ElementParser::new(&body);
+        let popup = parser.element_from_def(&PopupWindowDef::new("PW")).unwrap();
+        assert!(popup.press_button("Yes").is_ok());
+        let Err(WebDynproError::Element(err)) = popup.press_button("Later") else {
+            panic!("expected an element error");
+        };
+        assert!(
+            matches!(*err, ElementError::InvalidContent { ref element, .. } if element == "B4"),
+            "expected InvalidContent, got {err:?}"
+        );
+    }
 }
@@ -3,9 +3,9 @@ use std::{borrow::Cow, cell::OnceCell};
 use crate::{
     WdElement, WdLsData,
     element::property::{ScrollingMode, Visibility},
+    wd_event,
 };
 
-// TODO: Implement additional events and data
 #[doc = "[`ScrollContainer`] 내부 데이터"]
 #[derive(WdLsData)]
 #[allow(unused)]
@@ -51,3 +51,68 @@ pub struct ScrollContainer<'a> {
     #[wd_element(lsevents_field)]
     lsevents: OnceCell<Option<crate::element::EventParameterMap>>,
 }
+
+impl<'a> ScrollContainer<'a> {
+    /// 컨테이너의 스크롤 위치를 변경하는 이벤트를 반환합니다.
+    #[wd_event(name = "Scroll", params(scroll_top: i32 => "ScrollTop", scroll_left: i32 => "ScrollLeft"))]
+    pub fn scroll(&self, scroll_top: i32, scroll_left: i32) {}
+
+    /// 현재 세로 스크롤 위치를 반환합니다.
+    pub fn scroll_top(&self) -> i32 {
+        use crate::element::Element as _;
+        self.lsdata().scroll_top().copied().unwrap_or(0)
+    }
+
+    /// 현재 가로 스크롤 위치를 반환합니다.
+    pub fn scroll_left(&self) -> i32 {
+        use crate::element::Element as _;
+        self.lsdata().scroll_left().copied().unwrap_or(0)
+    }
+
+    /// LSData의 `custom_style`에 픽셀 단위로 지정된 컨테이너의 높이를 반환합니다.
+    pub fn page_height(&self) -> Option<i32> {
+        use crate::element::Element as _;
+        self.lsdata()
+            .custom_style()?
+            .split(';')
+            .find_map(|decl| {
+                let (name, value) = decl.split_once(':')?;
+                name.trim()
+                    .eq_ignore_ascii_case("height")
+                    .then_some(value.trim())
+            })?
+            .trim_end_matches("px")
+            .parse::<f64>()
+            .ok()
+            .map(|height| height as i32)
+    }
+
+    /// 세로 방향으로 한 페이지([`ScrollContainer::page_height()`])만큼 스크롤했을 때의 위치를 반환합니다.
+    /// 컨테이너의 높이를 알 수 없다면 `None`을 반환합니다.
+    pub fn next_page_scroll_top(&self) -> Option<i32> {
+        Some(self.scroll_top().saturating_add(self.page_height()?.max(0)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use scraper::{Html, Selector};
+
+    use super::ScrollContainer;
+
+    #[test]
+    fn next_page_scroll_top_from_custom_style() {
+        let document = Html::parse_fragment(
+            r#"<div ct="SC" id="SC1" lsdata="{6:120,10:'width:100%;height:300px'}"></div><div ct="SC" id="SC2" lsdata="{6:120}"></div>"#,
+        );
+        let container = |id: &str| {
+            let selector = Selector::parse(&format!(r#"[id="{id}"]"#)).unwrap();
+            ScrollContainer::new(
+                id.to_string().into(),
+                document.select(&selector).next().unwrap(),
+            )
+        };
+        assert_eq!(container("SC1").next_page_scroll_top(), Some(420));
+        assert_eq!(container("SC2").next_page_scroll_top(), None);
+    }
+}
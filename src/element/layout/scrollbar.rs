@@ -1,6 +1,6 @@
 use std::{borrow::Cow, cell::OnceCell};
 
-use crate::{WdElement, WdLsData, element::property::Visibility};
+use crate::{WdElement, WdLsData, element::property::Visibility, wd_event};
 
 use self::property::ScrollDirection;
 
@@ -15,7 +15,6 @@ pub mod property {
     }
 }
 
-// TODO: Implement additional events and data
 #[doc = "[`Scrollbar`] 내부 데이터"]
 #[derive(WdLsData)]
 #[allow(unused)]
@@ -63,3 +62,75 @@ pub struct Scrollbar<'a> {
     #[wd_element(lsevents_field)]
     lsevents: OnceCell<Option<crate::element::EventParameterMap>>,
 }
+
+impl<'a> Scrollbar<'a> {
+    /// 스크롤 바의 값을 `value`로 변경하는 이벤트를 반환합니다.
+    #[wd_event(name = "Scroll", params(value: i32 => "Value"))]
+    pub fn scroll(&self, value: i32) {}
+
+    /// 현재 스크롤 바의 값을 반환합니다.
+    pub fn value(&self) -> i32 {
+        use crate::element::Element as _;
+        self.lsdata().value().copied().unwrap_or(0)
+    }
+
+    /// 한 페이지 아래로 스크롤했을 때의 값을 반환합니다.
+    /// 마지막 페이지의 시작 값(`maximum - large_change`)을 넘지 않으며, 이미 마지막 페이지라면 `None`을 반환합니다.
+    pub fn next_page_value(&self) -> Option<i32> {
+        use crate::element::Element as _;
+        let lsdata = self.lsdata();
+        let value = self.value();
+        let large_change = lsdata.large_change().copied().unwrap_or(0).max(0);
+        let minimum = lsdata.minimum().copied().unwrap_or(0);
+        let last = lsdata
+            .maximum()
+            .copied()?
+            .saturating_sub(large_change)
+            .max(minimum);
+        if value >= last {
+            return None;
+        }
+        Some(value.saturating_add(large_change.max(1)).min(last))
+    }
+
+    /// 한 페이지 위로 스크롤했을 때의 값을 반환합니다.
+    /// 이미 첫 페이지라면 `None`을 반환합니다.
+    pub fn previous_page_value(&self) -> Option<i32> {
+        use crate::element::Element as _;
+        let lsdata = self.lsdata();
+        let value = self.value();
+        let minimum = lsdata.minimum().copied().unwrap_or(0);
+        if value <= minimum {
+            return None;
+        }
+        let page = lsdata.large_change().copied().unwrap_or(1).max(1);
+        Some(value.saturating_sub(page).max(minimum))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use scraper::{Html, Selector};
+
+    use super::Scrollbar;
+
+    fn page_values(lsdata: &str) -> (Option<i32>, Option<i32>) {
+        let html = format!(r#"<div ct="SCB" id="SB" lsdata="{lsdata}"></div>"#);
+        let document = Html::parse_fragment(&html);
+        let selector = Selector::parse(r#"[id="SB"]"#).unwrap();
+        let scrollbar = Scrollbar::new("SB".into(), document.select(&selector).next().unwrap());
+        (scrollbar.next_page_value(), scrollbar.previous_page_value())
+    }
+
+    #[test]
+    fn next_page_clamped_to_last_page() {
+        assert_eq!(page_values("{0:0,1:100,2:0,3:30}"), (Some(30), None));
+        assert_eq!(page_values("{0:60,1:100,2:0,3:30}"), (Some(70), Some(30)));
+        assert_eq!(page_values("{0:70,1:100,2:0,3:30}"), (None, Some(40)));
+        assert_eq!(page_values("{0:10,1:20,2:0,3:30}"), (None, Some(0)));
+        assert_eq!(
+            page_values("{0:2147483600,1:2147483647,2:0,3:10}"),
+            (Some(2147483610), Some(2147483590))
+        );
+    }
+}
@@ -4,8 +4,9 @@ use scraper::Selector;
 
 use crate::{
     WdElement, WdLsData,
-    element::{Element, definition::ElementDefinition, property::Visibility},
-    error::BodyError,
+    element::{Element, ElementWrapper, definition::ElementDefinition, property::Visibility},
+    error::{BodyError, ElementError, WebDynproError},
+    event::Event,
     wd_event,
 };
 
@@ -102,7 +103,192 @@ impl<'a> TabStrip<'a> {
         first_visible_item_index: u32 => "FirstVisibleItemIndex"
     ))]
     pub fn tab_select(&self, item_id: &str, item_index: u32, first_visible_item_index: u32) {}
+
+    /// 탭 내부 [`TabStripItem`]의 목록을 반환합니다.
+    pub fn tabs(&self) -> Vec<TabStripItem<'a>> {
+        self.tab_items()
+            .filter_map(|def| {
+                let selector = def.selector().ok()?;
+                let eref = self.element_ref.select(&selector).next()?;
+                TabStripItem::from_ref(def, eref).ok()
+            })
+            .collect()
+    }
+
+    /// 현재 화면에 보이는 첫번째 탭의 인덱스를 반환합니다.
+    pub fn first_visible_item_index(&self) -> u32 {
+        self.lsdata()
+            .first_visible_item_idx()
+            .and_then(|index| u32::try_from(*index).ok())
+            .unwrap_or(0)
+    }
+
+    /// 현재 선택된 탭을 반환합니다.
+    ///
+    /// 탭의 `aria-selected` 속성으로 찾고, 찾을 수 없다면 `current_index`에 해당하는 탭을 반환합니다.
+    pub fn selected_tab(&self) -> Option<TabStripItem<'a>> {
+        let tabs = self.tabs();
+        if let Some(position) = tabs.iter().position(|tab| tab.is_selected()) {
+            return tabs.into_iter().nth(position);
+        }
+        let current = u32::try_from(*self.lsdata().current_index()?).ok()?;
+        tabs.into_iter()
+            .enumerate()
+            .find(|(position, tab)| tab.index().unwrap_or(*position as u32) == current)
+            .map(|(_, tab)| tab)
+    }
+
+    /// 현재 선택된 탭의 내용에 해당하는 엘리먼트들을 반환합니다.
+    ///
+    /// 선택된 탭 엘리먼트가 하위 엘리먼트를 가지고 있다면 이를 반환하고, 그렇지 않다면 탭을 제외한 [`TabStrip`]의 하위 엘리먼트를 반환합니다.
+    pub fn selected_tab_content(&self) -> Vec<ElementWrapper<'a>> {
+        if let Some(tab) = self.selected_tab() {
+            let children = tab.children();
+            if !children.is_empty() {
+                return children;
+            }
+        }
+        self.children()
+            .into_iter()
+            .filter(|child| !matches!(child, ElementWrapper::TabStripItem(_)))
+            .collect()
+    }
+
+    /// 주어진 순서의 탭을 선택하는 이벤트를 반환합니다.
+    ///
+    /// 탭이 현재 화면에 보이지 않는다면 해당 탭이 첫번째로 보이도록 스크롤합니다.
+    pub fn select_tab_by_index(&self, index: usize) -> Result<Event, WebDynproError> {
+        let tab =
+            self.tabs()
+                .into_iter()
+                .nth(index)
+                .ok_or_else(|| ElementError::NoSuchContent {
+                    element: self.id().to_string(),
+                    content: format!("TabStripItem at index {index}"),
+                })?;
+        self.select_tab(&tab, index)
+    }
+
+    /// 주어진 제목을 가진 탭을 선택하는 이벤트를 반환합니다.
+    ///
+    /// 제목은 앞뒤 공백을 무시하고 비교하며, 일치하는 탭이 없다면 탭 제목의 목록을 담은 [`ElementError::NoSuchOption`] 오류를 반환합니다.
+    pub fn select_tab_by_caption(&self, caption: &str) -> Result<Event, WebDynproError> {
+        let tabs = self.tabs();
+        let Some(index) = tabs.iter().position(|tab| {
+            tab.caption()
+                .is_some_and(|text| text.trim() == caption.trim())
+        }) else {
+            return Err(ElementError::NoSuchOption {
+                element: self.id().to_string(),
+                text: caption.to_string(),
+                available: tabs
+                    .iter()
+                    .filter_map(|tab| tab.caption().map(str::to_owned))
+                    .collect(),
+            })?;
+        };
+        self.select_tab(&tabs[index], index)
+    }
+
+    fn select_tab(&self, tab: &TabStripItem<'a>, position: usize) -> Result<Event, WebDynproError> {
+        if !tab.enabled() {
+            return Err(ElementError::InvalidContent {
+                element: tab.id().to_string(),
+                content: "enabled TabStripItem".to_string(),
+            })?;
+        }
+        let item_index = tab.index().unwrap_or(position as u32);
+        let first_visible = self.first_visible_item_index();
+        let first_visible = if item_index < first_visible || tab.is_hidden() {
+            item_index
+        } else {
+            first_visible
+        };
+        self.tab_select(tab.id(), item_index, first_visible)
+    }
 }
 
 /// [`TabStrip`] 내부 아이템
 pub mod item;
+
+#[cfg(test)]
+mod test {
+    use super::TabStripDef;
+    use crate::{
+        body::test::body,
+        element::{Element as _, parser::ElementParser},
+        error::{ElementError, WebDynproError},
+        event::Event,
+    };
+
+    const TAB_STRIP: TabStripDef = TabStripDef::new("TS");
+
+    fn tab_strip_html(selected: Option<usize>) -> String {
+        let tabs = (0..4)
+            .map(|index| {
+                let selected = if selected == Some(index) {
+                    r#" aria-selected="true""#
+                } else {
+                    ""
+                };
+                format!(
+                    r#"<div ct="TSITM_standards" id="T{index}" lsdata="{{1:{index},2:'Tab {index}'}}"{selected}></div>"#
+                )
+            })
+            .collect::<String>();
+        format!(
+            r#"<div ct="TS_standards" id="TS" lsdata="{{0:2,5:2}}" lsevents="{{'TabSelect':[{{}},{{}}]}}">{tabs}</div>"#
+        )
+    }
+
+    fn params(event: &Event) -> (&str, &str, &str) {
+        let params = event.parameters();
+        (
+            params["ItemId"].as_str(),
+            params["ItemIndex"].as_str(),
+            params["FirstVisibleItemIndex"].as_str(),
+        )
+    }
+
+    #[test]
+    fn selected_tab_by_aria_or_current_index() {
+        let selected = body(&tab_strip_html(Some(1)));
+        let parser = ElementParser::new(&selected);
+        let tab_strip = parser.element_from_def(&TAB_STRIP).unwrap();
+        assert_eq!(tab_strip.tabs().len(), 4);
+        assert_eq!(tab_strip.selected_tab().unwrap().id(), "T1");
+
+        let unselected = body(&tab_strip_html(None));
+        let parser = ElementParser::new(&unselected);
+        let tab_strip = parser.element_from_def(&TAB_STRIP).unwrap();
+        assert_eq!(tab_strip.selected_tab().unwrap().id(), "T2");
+    }
+
+    #[test]
+    fn select_tab_adjusts_first_visible() {
+        let body = body(&tab_strip_html(Some(2)));
+        let parser = ElementParser::new(&body);
+        let tab_strip = parser.element_from_def(&TAB_STRIP).unwrap();
+        let event = tab_strip.select_tab_by_index(0).unwrap();
+        assert_eq!(params(&event), ("T0", "0", "0"));
+        let event = tab_strip.select_tab_by_index(3).unwrap();
+        assert_eq!(params(&event), ("T3", "3", "2"));
+        assert!(tab_strip.select_tab_by_index(4).is_err());
+    }
+
+    #[test]
+    fn select_tab_by_caption() {
+        let body = body(&tab_strip_html(Some(2)));
+        let parser = ElementParser::new(&body);
+        let tab_strip = parser.element_from_def(&TAB_STRIP).unwrap();
+        let event = tab_strip.select_tab_by_caption(" Tab 1 ").unwrap();
+        assert_eq!(params(&event), ("T1", "1", "1"));
+        let Err(WebDynproError::Element(err)) = tab_strip.select_tab_by_caption("Tab 9") else {
+            panic!("expected an element error");
+        };
+        let ElementError::NoSuchOption { available, .. } = *err else {
+            panic!("expected NoSuchOption, got {err:?}");
+        };
+        assert_eq!(available, vec!["Tab 0", "Tab 1", "Tab 2", "Tab 3"]);
+    }
+}
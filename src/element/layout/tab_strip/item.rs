@@ -1,6 +1,9 @@
 use std::{borrow::Cow, cell::OnceCell};
 
-use crate::{WdElement, WdLsData, element::property::Visibility};
+use crate::{
+    WdElement, WdLsData,
+    element::{Element as _, property::Visibility, utils::is_hidden},
+};
 
 // Note: This element renders as "TSITM_ie6" if >= IE6
 #[doc = "[`TabStripItem`] 내부 데이터"]
@@ -61,3 +64,39 @@ pub struct TabStripItem<'a> {
     #[wd_element(lsdata_field)]
     lsdata: OnceCell<TabStripItemLSData>,
 }
+
+impl<'a> TabStripItem<'a> {
+    /// 탭의 제목을 반환합니다.
+    pub fn caption(&self) -> Option<&str> {
+        self.lsdata().caption().map(String::as_str)
+    }
+
+    /// [`TabStrip`](crate::element::layout::TabStrip) 내에서 탭의 인덱스를 반환합니다.
+    pub fn index(&self) -> Option<u32> {
+        self.lsdata()
+            .index()
+            .and_then(|index| u32::try_from(*index).ok())
+    }
+
+    /// 탭이 활성화되어 있는지 여부를 반환합니다.
+    pub fn enabled(&self) -> bool {
+        self.lsdata().enabled().copied().unwrap_or(true)
+    }
+
+    /// 탭이 선택되어 있는지 여부를 반환합니다.
+    pub fn is_selected(&self) -> bool {
+        self.element_ref
+            .value()
+            .attr("aria-selected")
+            .is_some_and(|value| value == "true")
+    }
+
+    /// 탭이 스크롤되어 화면에 보이지 않는 상태인지 여부를 반환합니다.
+    pub fn is_hidden(&self) -> bool {
+        is_hidden(&self.element_ref)
+            || matches!(
+                self.lsdata().visibility(),
+                Some(Visibility::None | Visibility::Blank)
+            )
+    }
+}
@@ -3,6 +3,7 @@ use std::{borrow::Cow, cell::OnceCell};
 use crate::{
     WdElement, WdLsData,
     element::property::{ScrollingMode, Visibility},
+    wd_event,
 };
 
 use self::property::TrayDesign;
@@ -18,7 +19,6 @@ pub mod property {
     }
 }
 
-// TODO: Implement additional events and data
 #[doc = "[`Tray`] 내부 데이터"]
 #[derive(WdLsData)]
 #[allow(unused)]
@@ -90,3 +90,37 @@ pub struct Tray<'a> {
     #[wd_element(lsevents_field)]
     lsevents: OnceCell<Option<crate::element::EventParameterMap>>,
 }
+
+impl<'a> Tray<'a> {
+    /// 트레이를 펼치거나(`expand`가 참일 경우) 접는 이벤트를 반환합니다.
+    #[wd_event(name = "Toggle", params(expand: bool => "Expand"))]
+    pub fn toggle(&self, expand: bool) {}
+
+    /// 트레이를 닫는 이벤트를 반환합니다.
+    #[wd_event(name = "Close")]
+    pub fn close(&self) {}
+
+    /// 트레이의 옵션 메뉴를 여는 이벤트를 반환합니다.
+    #[wd_event(name = "ShowOptionMenu")]
+    pub fn show_option_menu(&self) {}
+
+    /// 이 [`Tray`]가 접혀 있는지 여부를 반환합니다.
+    pub fn collapsed(&self) -> bool {
+        use crate::element::Element as _;
+        self.lsdata().collapsed().copied().unwrap_or(false)
+    }
+
+    /// 이 [`Tray`]의 내용이 실제로 렌더링되어 있는지 여부를 반환합니다.
+    ///
+    /// 접힌 트레이는 서버에서 내용을 렌더링하지 않으므로, 트레이가 펼쳐져 있고 하위 엘리먼트가 존재할 때만 참을 반환합니다.
+    pub fn is_content_rendered(&self) -> bool {
+        use crate::element::Element as _;
+        if self.collapsed() {
+            return false;
+        }
+        let Ok(selector) = scraper::Selector::parse("[ct]") else {
+            return false;
+        };
+        self.element_ref().select(&selector).next().is_some()
+    }
+}
@@ -1,9 +1,11 @@
 use crate::body::Body;
+use crate::element::layout::{PopupWindow, PopupWindowDef};
 use crate::element::sub::SubElement;
 use crate::element::sub::definition::SubElementDefinition;
+use crate::element::utils::{is_hidden, style_value};
 use crate::element::{Element, definition::ElementDefinition};
 use crate::error::{ElementError, WebDynproError};
-use scraper::Html;
+use scraper::{ElementRef, Html, Selector};
 
 /// DOM에서 엘리먼트를 파싱하기 위한 파서
 pub struct ElementParser(Html);
@@ -52,4 +54,96 @@ impl<'s> ElementParser {
     pub fn document(&'s self) -> &'s Html {
         &self.0
     }
+
+    /// 문서에 표시된 [`PopupWindow`]를 문서 순서대로 반환합니다. 숨겨진 창은 제외합니다.
+    pub fn popup_windows(&'s self) -> Vec<PopupWindow<'s>> {
+        let Ok(selector) = Selector::parse(&format!(r#"[ct="{}"]"#, PopupWindow::CONTROL_ID))
+        else {
+            return vec![];
+        };
+        self.0
+            .select(&selector)
+            .filter(|eref| !is_hidden(eref))
+            .filter_map(|eref| {
+                let def = PopupWindowDef::from_ref(eref).ok()?;
+                PopupWindow::from_ref(&def, eref).ok()
+            })
+            .collect()
+    }
+
+    /// 가장 위에 표시된 [`PopupWindow`]를 반환합니다.
+    ///
+    /// 모달 창이 있다면 모달 창 중에서 찾으며, `z-index`가 가장 큰 창을, `z-index`가 같다면 문서에서 나중에 나타난 창을 가장 위의 창으로 취급합니다.
+    pub fn active_popup(&'s self) -> Option<PopupWindow<'s>> {
+        self.popup_windows()
+            .into_iter()
+            .enumerate()
+            .max_by_key(|(order, popup)| (popup.is_modal(), z_index(popup.element_ref()), *order))
+            .map(|(_, popup)| popup)
+    }
+}
+
+fn z_index(eref: &ElementRef) -> i64 {
+    style_value(eref, "z-index")
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::ElementParser;
+    use crate::body::test::body;
+    use crate::command::WebDynproCommandExecutor as _;
+    use crate::command::element::layout::ActivePopupWindowCommand;
+    use crate::element::Element as _;
+    use crate::element::definition::ElementDefinition as _;
+
+    fn popup(id: &str, style: &str) -> String {
+        format!(r#"<div ct="PW" id="{id}" lsdata="{{}}" style="{style}"></div>"#)
+    }
+
+    fn active_popup_id(content: &str) -> Option<String> {
+        let body = body(content);
+        let parser = ElementParser::new(&body);
+        let from_parser = parser.active_popup().map(|popup| popup.id().to_owned());
+        let from_command = parser
+            .read(ActivePopupWindowCommand::new())
+            .unwrap()
+            .map(|def| def.id().to_owned());
+        assert_eq!(from_parser, from_command);
+        from_parser
+    }
+
+    #[test]
+    fn active_popup_of_stacked_popups() {
+        let stacked = [popup("PW1", ""), popup("PW2", "")].concat();
+        assert_eq!(active_popup_id(&stacked).as_deref(), Some("PW2"));
+        let z_indexed = [popup("PW1", "z-index: 20"), popup("PW2", "z-index:10")].concat();
+        assert_eq!(active_popup_id(&z_indexed).as_deref(), Some("PW1"));
+        assert_eq!(active_popup_id(""), None);
+    }
+
+    #[test]
+    fn active_popup_prefers_modal_popups() {
+        let content = [
+            r#"<div ct="PW" id="PW1" lsdata="{12:'MODAL'}" style="z-index:10"></div>"#,
+            r#"<div ct="PW" id="PW2" lsdata="{12:'AMODAL'}" style="z-index:20"></div>"#,
+        ]
+        .concat();
+        assert_eq!(active_popup_id(&content).as_deref(), Some("PW1"));
+    }
+
+    #[test]
+    fn active_popup_skips_hidden_popups() {
+        let display_none = [popup("PW1", ""), popup("PW2", "display: none")].concat();
+        assert_eq!(active_popup_id(&display_none).as_deref(), Some("PW1"));
+        let aria_hidden = [
+            popup("PW1", ""),
+            r#"<div ct="PW" id="PW2" lsdata="{}" aria-hidden="true"></div>"#.to_string(),
+        ]
+        .concat();
+        assert_eq!(active_popup_id(&aria_hidden).as_deref(), Some("PW1"));
+        let body = body(&display_none);
+        assert_eq!(ElementParser::new(&body).popup_windows().len(), 1);
+    }
 }
@@ -95,7 +95,7 @@ pub enum LockedDesign {
 
 /// 추천 필터 분류
 #[allow(missing_docs)]
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Copy, Deserialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SuggestFilterType {
     Off,
@@ -106,7 +106,7 @@ pub enum SuggestFilterType {
 
 /// 추천 필터 조건
 #[allow(missing_docs)]
-#[derive(Clone, Deserialize, Debug)]
+#[derive(Clone, Copy, Deserialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SuggestFilterCondition {
     Value1OrValue2,
@@ -214,6 +214,18 @@ pub enum SortState {
     Descending,
 }
 
+impl std::fmt::Display for SortState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            SortState::Disabled => "DISABLED",
+            SortState::None => "NONE",
+            SortState::Ascending => "ASCENDING",
+            SortState::Descending => "DESCENDING",
+        };
+        write!(f, "{str}")
+    }
+}
+
 /// 수직 텍스트 정렬
 #[allow(missing_docs)]
 #[derive(Clone, Deserialize, Debug)]
@@ -1,8 +1,10 @@
 mod combo_box;
 
-pub use self::combo_box::{ComboBox, ComboBoxDef, ComboBoxLSData, property::ComboBoxBehavior};
+pub use self::combo_box::{
+    ComboBox, ComboBoxDef, ComboBoxLSData, ComboBoxTextMatch, property::ComboBoxBehavior,
+};
 
-pub use self::check_box::CheckBox;
+pub use self::check_box::{CheckBox, CheckBoxDef, CheckBoxLSData};
 /// [`ListBox`](list_box::ListBox) 구현
 pub mod list_box;
 
@@ -1,6 +1,6 @@
 use std::{borrow::Cow, cell::OnceCell};
 
-use crate::{WdElement, WdLsData, element::property::Visibility};
+use crate::{WdElement, WdLsData, element::property::Visibility, wd_event};
 
 #[doc = "[`CheckBox`]의 내부 데이터"]
 #[derive(WdLsData)]
@@ -98,6 +98,10 @@ impl<'a> CheckBox<'a> {
             .attr("aria-invalid")
             .is_some_and(|str| str == "true")
     }
+
+    /// 체크박스의 체크 상태를 `checked`로 변경하는 이벤트를 반환합니다.
+    #[wd_event(name = "Toggle", params(checked: bool => "Checked"))]
+    pub fn toggle(&self, checked: bool) {}
 }
 
 impl std::fmt::Display for CheckBox<'_> {
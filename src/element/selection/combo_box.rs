@@ -10,7 +10,8 @@ use self::property::ComboBoxBehavior;
 use crate::element::parser::ElementParser;
 use crate::element::{Element, ElementDefWrapper};
 
-use super::list_box::ListBoxDefWrapper;
+use super::list_box::{ListBoxDefWrapper, item::ListBoxItemInfo};
+use crate::event::Event;
 use crate::{WdElement, WdLsData, wd_event};
 
 pub mod property {
@@ -27,6 +28,33 @@ pub mod property {
     }
 }
 
+/// [`ComboBox`]의 선택지를 표시되는 텍스트로 찾을 때의 비교 방식
+///
+/// 모든 방식은 비교 전에 앞뒤 공백을 제거합니다.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ComboBoxTextMatch {
+    /// 텍스트가 정확히 일치
+    #[default]
+    Exact,
+    /// 대소문자를 구분하지 않고 일치
+    CaseInsensitive,
+    /// 선택지가 주어진 텍스트로 시작
+    Prefix,
+}
+
+impl ComboBoxTextMatch {
+    /// 선택지의 텍스트 `option`이 찾는 텍스트 `text`와 일치하는지 확인합니다.
+    pub fn matches(&self, option: &str, text: &str) -> bool {
+        let option = option.trim();
+        let text = text.trim();
+        match self {
+            ComboBoxTextMatch::Exact => option == text,
+            ComboBoxTextMatch::CaseInsensitive => option.to_lowercase() == text.to_lowercase(),
+            ComboBoxTextMatch::Prefix => option.starts_with(text),
+        }
+    }
+}
+
 #[doc = "[`ComboBox`] 내부 데이터"]
 #[derive(WdLsData)]
 #[allow(unused)]
@@ -139,6 +167,95 @@ impl<'a> ComboBox<'a> {
         )
     }
 
+    /// [`ComboBox`]의 선택지 목록을 [`ListBoxItemInfo`]로 반환합니다.
+    pub fn item_infos(
+        &self,
+        parser: &ElementParser,
+    ) -> Result<Vec<ListBoxItemInfo>, WebDynproError> {
+        self.item_list_box(parser)?.value(parser)?.item_infos()
+    }
+
+    /// 표시되는 텍스트와 일치하는 선택지의 키를 반환합니다.
+    ///
+    /// 먼저 선택지의 `value1`을 비교하고, 일치하는 선택지가 없다면 `value2`를 비교합니다.
+    /// 일치하는 선택지가 여러 개라면 첫번째 선택지의 키를 반환합니다.
+    pub fn key_for_text(
+        &self,
+        text: &str,
+        match_mode: ComboBoxTextMatch,
+        parser: &ElementParser,
+    ) -> Result<String, WebDynproError> {
+        let items = self.item_infos(parser)?;
+        let options = items
+            .iter()
+            .filter_map(|info| match info {
+                ListBoxItemInfo::Item {
+                    key,
+                    value1,
+                    value2,
+                    ..
+                } => Some((key, value1, value2)),
+                ListBoxItemInfo::ActionItem { .. } => None,
+            })
+            .collect::<Vec<_>>();
+        let found = options
+            .iter()
+            .find(|(_, value1, _)| match_mode.matches(value1, text))
+            .or_else(|| {
+                options
+                    .iter()
+                    .find(|(_, _, value2)| match_mode.matches(value2, text))
+            });
+        match found {
+            Some((key, _, _)) => Ok(key.to_string()),
+            None => Err(ElementError::NoSuchOption {
+                element: self.id().to_string(),
+                text: text.to_string(),
+                available: options
+                    .iter()
+                    .map(|(_, value1, _)| value1.to_string())
+                    .collect(),
+            })?,
+        }
+    }
+
+    /// 주어진 키를 가진 선택지의 표시되는 텍스트(`value1`)를 반환합니다.
+    pub fn text_for_key(
+        &self,
+        key: &str,
+        parser: &ElementParser,
+    ) -> Result<String, WebDynproError> {
+        self.item_infos(parser)?
+            .into_iter()
+            .find_map(|info| match info {
+                ListBoxItemInfo::Item {
+                    key: item_key,
+                    value1,
+                    ..
+                } if item_key == key => Some(value1),
+                _ => None,
+            })
+            .ok_or_else(|| {
+                ElementError::NoSuchContent {
+                    element: self.id().to_string(),
+                    content: format!("ListBoxItem with key {key}"),
+                }
+                .into()
+            })
+    }
+
+    /// 표시되는 텍스트와 일치하는 선택지를 선택하는 이벤트를 반환합니다.
+    pub fn select_by_text(
+        &self,
+        text: &str,
+        match_mode: ComboBoxTextMatch,
+        by_enter: bool,
+        parser: &ElementParser,
+    ) -> Result<Event, WebDynproError> {
+        let key = self.key_for_text(text, match_mode, parser)?;
+        self.select(&key, by_enter)
+    }
+
     /// 선택지를 선택하는 이벤트를 반환합니다. `by_enter`가 참일 경우 엔터를 눌러 선택한 것으로 취급합니다.
     #[wd_event(name = "Select", params(key: &str => "Key", by_enter: bool => "ByEnter"))]
     pub fn select(&self, key: &str, by_enter: bool) {}
@@ -147,6 +264,58 @@ impl<'a> ComboBox<'a> {
     #[wd_event(name = "Change", params(value: &str => "Value"))]
     pub fn change(&self, value: &str) {}
 
+    /// 입력 중인 값에 대한 추천 목록(type-ahead)을 서버에 요청하는 이벤트를 반환합니다.
+    #[wd_event(name = "Suggest", params(value: &str => "Value"))]
+    pub fn suggest(&self, value: &str) {}
+
+    /// 서버에 추천 목록을 요청하는 [`ComboBox`]인지 여부를 반환합니다.
+    pub fn is_server_suggest(&self) -> bool {
+        matches!(
+            self.lsdata().suggest_filter(),
+            Some(
+                SuggestFilterType::Server
+                    | SuggestFilterType::ClientServer
+                    | SuggestFilterType::ClientServerPrefix
+            )
+        )
+    }
+
+    /// 입력한 값에 대한 추천 선택지를 반환합니다.
+    ///
+    /// `suggest_filter`와 `suggest_filter_condition`에 따라 선택지를 거르며, 서버에서 이미 필터링한 목록이라면 그대로 반환합니다.
+    pub fn suggestions(
+        &self,
+        value: &str,
+        parser: &ElementParser,
+    ) -> Result<Vec<ListBoxItemInfo>, WebDynproError> {
+        let prefix = matches!(
+            self.lsdata().suggest_filter(),
+            Some(SuggestFilterType::ClientServerPrefix)
+        );
+        let condition = self.lsdata().suggest_filter_condition().copied();
+        self.item_list_box(parser)?
+            .value(parser)?
+            .suggestions(value, condition, prefix)
+    }
+
+    /// 이 [`ComboBox`]가 읽기 전용인지 여부를 반환합니다.
+    pub fn readonly(&self) -> bool {
+        let element = self.element_ref.value();
+        element.attr("readonly").is_some()
+            || element
+                .attr("aria-readonly")
+                .is_some_and(|str| str == "true")
+    }
+
+    /// 이 [`ComboBox`]가 비활성화 상태인지 여부를 반환합니다.
+    pub fn disabled(&self) -> bool {
+        let element = self.element_ref.value();
+        element.attr("disabled").is_some()
+            || element
+                .attr("aria-disabled")
+                .is_some_and(|str| str == "true")
+    }
+
     /// 이 [`ComboBox`]의 값을 가져옵니다.
     pub fn value(&self) -> Option<&str> {
         self.element_ref.attr("value")
@@ -1,12 +1,18 @@
 use std::{borrow::Cow, cell::OnceCell};
 
+use indexmap::IndexMap;
+
 use crate::{
     WdLsData,
-    element::{ElementDefWrapper, EventParameterMap},
-    error::WebDynproError,
+    element::{
+        ElementDefWrapper, EventParameterMap, Interactable as _, property::SuggestFilterCondition,
+    },
+    error::{ElementError, WebDynproError},
+    event::Event,
 };
 
 use self::item::{ListBoxItemDefWrapper, ListBoxItemInfo};
+use self::table_data::{ListBoxTableDataDefinition, ListBoxTableDataItem, parse_table_data_items};
 
 macro_rules! def_listbox_subset {
     [$({
@@ -75,14 +81,7 @@ macro_rules! def_listbox_subset {
             type Def = $def_name;
 
             fn lsdata(&self) -> &Self::ElementLSData {
-                self.list_box().lsdata
-                    .get_or_init(|| {
-                        let lsdata_attr = self.element_ref().value().attr("lsdata").unwrap_or("");
-                        let Ok(lsdata_obj) = $crate::element::utils::parse_lsdata(lsdata_attr).inspect_err(|e| tracing::warn!(?e, "failed to parse lsdata")) else {
-                            return ListBoxLSData::default();
-                        };
-                        serde_json::from_value::<Self::ElementLSData>(lsdata_obj).unwrap_or(ListBoxLSData::default())
-                    })
+                self.list_box().lsdata()
             }
 
             fn from_ref(elem_def: &impl $crate::element::definition::ElementDefinition<'a>, element: scraper::ElementRef<'a>) -> Result<Self, $crate::error::WebDynproError> {
@@ -122,6 +121,24 @@ macro_rules! def_listbox_subset {
             pub const fn new(id: std::borrow::Cow<'static, str>, element_ref: scraper::ElementRef<'a>) -> Self {
                 Self($crate::element::selection::list_box::ListBox::new(id, element_ref))
             }
+
+            /// 주어진 키를 가진 아이템을 선택하는 이벤트를 반환합니다.
+            pub fn select(&self, key: &str) -> Result<Event, WebDynproError> {
+                self.item_event("Select", key)
+            }
+
+            /// 주어진 키를 가진 아이템의 선택을 해제하는 이벤트를 반환합니다.
+            pub fn deselect(&self, key: &str) -> Result<Event, WebDynproError> {
+                self.item_event("Deselect", key)
+            }
+
+            fn item_event(&self, event: &str, key: &str) -> Result<Event, WebDynproError> {
+                self.list_box().ensure_item_key(key)?;
+                let mut parameters: IndexMap<String, String> = IndexMap::new();
+                parameters.insert("Id".to_string(), self.list_box().id.to_string());
+                parameters.insert("Key".to_string(), key.to_string());
+                self.fire_event(event.to_string(), parameters)
+            }
         }
 
         inventory::submit! {
@@ -193,6 +210,46 @@ macro_rules! def_listbox_subset {
                 $(ListBoxWrapper::$name(elem) => elem.list_box(),)+
             }
         }
+
+        /// 아이템들의 [`ListBoxItemInfo`]를 반환합니다.
+        pub fn item_infos(&self) -> Result<Vec<ListBoxItemInfo>, WebDynproError> {
+            match self {
+                $(ListBoxWrapper::$name(elem) => Ok(elem.list_box().item_infos()?.collect()),)+
+            }
+        }
+
+        /// 입력한 값에 대한 추천 아이템을 반환합니다. 자세한 동작은 [`ListBox::suggestions`]를 참고하세요.
+        pub fn suggestions(&self, value: &str, condition: Option<SuggestFilterCondition>, prefix: bool) -> Result<Vec<ListBoxItemInfo>, WebDynproError> {
+            match self {
+                $(ListBoxWrapper::$name(elem) => elem.list_box().suggestions(value, condition, prefix),)+
+            }
+        }
+
+        /// 주어진 키를 가진 아이템을 선택하는 이벤트를 반환합니다.
+        pub fn select(&self, key: &str) -> Result<Event, WebDynproError> {
+            match self {
+                $(ListBoxWrapper::$name(elem) => elem.select(key),)+
+            }
+        }
+
+        /// 주어진 키를 가진 아이템의 선택을 해제하는 이벤트를 반환합니다.
+        pub fn deselect(&self, key: &str) -> Result<Event, WebDynproError> {
+            match self {
+                $(ListBoxWrapper::$name(elem) => elem.deselect(key),)+
+            }
+        }
+
+        /// 주어진 키들을 가진 아이템들을 선택하는 이벤트를 반환합니다.
+        /// [`ListBoxMultiple`]이 아닌 경우 [`ElementError::InvalidContent`] 오류를 반환합니다.
+        pub fn multi_select(&self, keys: &[&str]) -> Result<Event, WebDynproError> {
+            match self {
+                ListBoxWrapper::ListBoxMultiple(elem) => elem.multi_select(keys),
+                _ => Err(ElementError::InvalidContent {
+                    element: self.unwrap().id.to_string(),
+                    content: "ListBox which allows multiple selection".to_string(),
+                })?,
+            }
+        }
     }
 };
 }
@@ -322,7 +379,10 @@ impl<'a> ListBox<'a> {
         }
     }
 
-    /// [`ListBoxItemDefWrapper`]의 목록을 반환합니다.
+    /// HTML로 렌더링된 아이템의 [`ListBoxItemDefWrapper`] 목록을 반환합니다.
+    ///
+    /// `item_table_data`에 JSON으로 전달된 아이템은 [`ListBox::table_data_items()`]로 얻을 수 있으며,
+    /// 두 종류의 아이템을 함께 다루려면 [`ListBox::item_infos()`]를 사용하세요.
     pub fn items(&self) -> impl Iterator<Item = &ListBoxItemDefWrapper> {
         self.items
             .get_or_init(|| {
@@ -346,17 +406,174 @@ impl<'a> ListBox<'a> {
             .iter()
     }
 
+    /// [`ListBoxLSData`]를 반환합니다.
+    pub fn lsdata(&self) -> &ListBoxLSData {
+        self.lsdata.get_or_init(|| {
+            let lsdata_attr = self.element_ref.value().attr("lsdata").unwrap_or("");
+            let Ok(lsdata_obj) = crate::element::utils::parse_lsdata(lsdata_attr)
+                .inspect_err(|e| tracing::warn!(?e, "failed to parse lsdata"))
+            else {
+                return ListBoxLSData::default();
+            };
+            serde_json::from_value::<ListBoxLSData>(lsdata_obj).unwrap_or_default()
+        })
+    }
+
+    /// `table_data_definition`에 정의된 JSON 아이템의 열 정의를 반환합니다.
+    pub fn table_data_definition(
+        &self,
+    ) -> Result<Option<ListBoxTableDataDefinition>, WebDynproError> {
+        self.lsdata()
+            .table_data_definition()
+            .filter(|raw| !raw.trim().is_empty())
+            .map(|raw| ListBoxTableDataDefinition::parse(raw))
+            .transpose()
+    }
+
+    /// `item_table_data`에 JSON으로 전달된 아이템의 목록을 반환합니다.
+    pub fn table_data_items(&self) -> Result<Vec<ListBoxTableDataItem>, WebDynproError> {
+        self.parse_table_data(self.lsdata().item_table_data())
+    }
+
+    /// `history_table_data`에 JSON으로 전달된 최근 선택 아이템의 목록을 반환합니다.
+    pub fn history_items(&self) -> Result<Vec<ListBoxTableDataItem>, WebDynproError> {
+        self.parse_table_data(self.lsdata().history_table_data())
+    }
+
+    // 해석할 수 없는 `item_table_data`는 경고를 남기고 비어 있는 것으로 취급합니다.
+    fn table_data_items_lossy(&self) -> Vec<ListBoxTableDataItem> {
+        self.table_data_items()
+            .inspect_err(|e| tracing::warn!(?e, "failed to parse item table data"))
+            .unwrap_or_default()
+    }
+
+    fn parse_table_data(
+        &self,
+        raw: Option<&String>,
+    ) -> Result<Vec<ListBoxTableDataItem>, WebDynproError> {
+        let Some(raw) = raw.filter(|raw| !raw.trim().is_empty()) else {
+            return Ok(Vec::new());
+        };
+        parse_table_data_items(raw, self.table_data_definition()?.as_ref())
+    }
+
+    /// 입력한 값에 대한 추천 아이템을 반환합니다.
+    ///
+    /// 서버에서 이미 필터링된 목록(`filtered`)이라면 모든 아이템을 그대로 반환합니다.
+    /// 그렇지 않다면 대소문자를 구분하지 않고 `value1`(`condition`이 [`SuggestFilterCondition::Value1OrValue2`]라면 `value2`도)이
+    /// 입력한 값으로 시작하거나(`prefix`가 참일 경우), 입력한 값을 포함하는 아이템만 반환합니다.
+    pub fn suggestions(
+        &self,
+        value: &str,
+        condition: Option<SuggestFilterCondition>,
+        prefix: bool,
+    ) -> Result<Vec<ListBoxItemInfo>, WebDynproError> {
+        let items = self
+            .item_infos()?
+            .filter(|info| matches!(info, ListBoxItemInfo::Item { .. }));
+        if self.lsdata().filtered().copied().unwrap_or(false) {
+            return Ok(items.collect());
+        }
+        let needle = value.trim().to_lowercase();
+        let is_match = |text: &str| {
+            let text = text.to_lowercase();
+            if prefix {
+                text.starts_with(&needle)
+            } else {
+                text.contains(&needle)
+            }
+        };
+        Ok(items
+            .filter(|info| match info {
+                ListBoxItemInfo::Item { value1, value2, .. } => {
+                    is_match(value1)
+                        || (condition == Some(SuggestFilterCondition::Value1OrValue2)
+                            && is_match(value2))
+                }
+                ListBoxItemInfo::ActionItem { .. } => false,
+            })
+            .collect())
+    }
+
+    fn ensure_item_key(&self, key: &str) -> Result<(), WebDynproError> {
+        let items_selector = scraper::Selector::parse(r#"[ct="LIB_I"]"#).unwrap();
+        let exists = self
+            .element_ref
+            .select(&items_selector)
+            .any(|elem_ref| elem_ref.value().attr("data-itemkey") == Some(key))
+            || self
+                .table_data_items_lossy()
+                .iter()
+                .any(|item| item.key() == key);
+        if exists {
+            Ok(())
+        } else {
+            Err(ElementError::NoSuchContent {
+                element: self.id.to_string(),
+                content: format!("ListBoxItem with key {key}"),
+            })?
+        }
+    }
+
     /// [`ListBoxItemInfo`]의 Iterator를 반환합니다.
+    ///
+    /// HTML로 렌더링된 아이템 뒤에 `item_table_data`의 JSON 아이템 중 키가 겹치지 않는 아이템이 이어집니다.
+    /// `item_table_data`를 해석할 수 없다면 경고를 남기고 HTML로 렌더링된 아이템만 반환합니다.
     pub fn item_infos(&self) -> Result<impl Iterator<Item = ListBoxItemInfo>, WebDynproError> {
         let items_selector = scraper::Selector::parse("[ct]").unwrap();
-        let vec = self
+        let mut vec = self
             .element_ref
             .select(&items_selector)
             .map(|elem_ref| ListBoxItemInfo::from_element_ref(elem_ref))
             .collect::<Result<Vec<ListBoxItemInfo>, WebDynproError>>()?;
+        let table_items = self
+            .table_data_items_lossy()
+            .into_iter()
+            .filter(|item| {
+                !vec.iter().any(
+                    |info| matches!(info, ListBoxItemInfo::Item { key, .. } if key == item.key()),
+                )
+            })
+            .map(ListBoxItemInfo::from)
+            .collect::<Vec<ListBoxItemInfo>>();
+        vec.extend(table_items);
         Ok(vec.into_iter())
     }
 }
 
+const MULTI_SELECT_SEPARATOR: &str = ",";
+
+impl ListBoxMultiple<'_> {
+    /// 주어진 키들을 가진 아이템들을 선택하는 이벤트를 반환합니다.
+    ///
+    /// 키들은 `Keys` 파라메터에 `,`로 이어붙여 전달되므로, `,`를 포함하는 키는 [`ElementError::InvalidContent`] 오류를 반환합니다.
+    /// 존재하지 않는 키가 있다면 [`ElementError::NoSuchContent`] 오류를 반환합니다.
+    pub fn multi_select(&self, keys: &[&str]) -> Result<Event, WebDynproError> {
+        for key in keys {
+            if key.contains(MULTI_SELECT_SEPARATOR) {
+                return Err(ElementError::InvalidContent {
+                    element: self.list_box().id.to_string(),
+                    content: format!(
+                        "ListBoxItem key without separator '{MULTI_SELECT_SEPARATOR}': {key}"
+                    ),
+                })?;
+            }
+            self.list_box().ensure_item_key(key)?;
+        }
+        let mut parameters: IndexMap<String, String> = IndexMap::new();
+        parameters.insert("Id".to_string(), self.list_box().id.to_string());
+        parameters.insert("Keys".to_string(), keys.join(MULTI_SELECT_SEPARATOR));
+        self.fire_event("MultiSelect".to_string(), parameters)
+    }
+}
+
 /// [`ListBoxItem`](item::ListBoxItem)과 [`ListBoxActionItem`](item::ListBoxActionItem)이 포함된 모듈
 pub mod item;
+
+/// JSON으로 전달되는 [`ListBox`] 아이템을 해석하는 모듈
+///
+/// > |**참고**| 해석하는 형식은 실제 페이지에서 수집한 데이터로 검증되지 않았으므로, 이 모듈의 아이템은 [`ListBox::items()`]에 포함되지 않습니다.
+pub mod table_data;
+
+#[cfg(test)]
+mod test;
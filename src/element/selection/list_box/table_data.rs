@@ -0,0 +1,256 @@
+use serde_json::Value;
+
+use super::item::ListBoxItemInfo;
+use crate::error::{ElementError, WebDynproError};
+
+// 열 정의가 없을 때 키 열로 취급하는 열 이름
+const KEY_COLUMN: &str = "key";
+// 값 열이 아닌 아이템 속성을 나타내는 열 이름
+const INDEX_COLUMN: &str = "index";
+const SELECTED_COLUMN: &str = "selected";
+const ENABLED_COLUMN: &str = "enabled";
+const TITLE_COLUMN: &str = "title";
+
+/// JSON 기반 [`ListBox`](super::ListBox)의 `table_data_definition`을 해석한 열 정의
+///
+/// 열 정의는 열의 배열이며, 각 열은 이름 문자열이거나 `name`과 `key` 필드를 가진 오브젝트(`{"name":"CODE","key":true}`)입니다.
+/// `key`가 참인 열을 키 열로 취급하며, 키 열이 지정되지 않았다면 이름이 `key`인 열을, 그런 열도 없다면 첫번째 열을 키 열로 취급합니다.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ListBoxTableDataDefinition {
+    columns: Vec<String>,
+    key_column: Option<usize>,
+}
+
+impl ListBoxTableDataDefinition {
+    /// `table_data_definition` 문자열을 해석합니다.
+    pub fn parse(raw: &str) -> Result<Self, WebDynproError> {
+        let value = parse_table_json(raw)?;
+        let entries = value
+            .as_array()
+            .ok_or_else(|| invalid_table_data("table data definition"))?;
+        let mut columns = Vec::with_capacity(entries.len());
+        let mut key_column = None;
+        for (index, entry) in entries.iter().enumerate() {
+            let name = match entry {
+                Value::String(name) => name.to_owned(),
+                Value::Object(object) => {
+                    let is_key = object.get("key").and_then(Value::as_bool).unwrap_or(false);
+                    if is_key && key_column.is_none() {
+                        key_column = Some(index);
+                    }
+                    object
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .map(str::to_owned)
+                        .ok_or_else(|| invalid_table_data("name of table data column"))?
+                }
+                _ => Err(invalid_table_data("table data column"))?,
+            };
+            columns.push(name);
+        }
+        let key_column = key_column
+            .or_else(|| columns.iter().position(|name| name == KEY_COLUMN))
+            .or((!columns.is_empty()).then_some(0));
+        Ok(Self {
+            columns,
+            key_column,
+        })
+    }
+
+    /// 열 이름의 목록을 반환합니다.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// 키 열의 이름을 반환합니다.
+    pub fn key_column(&self) -> Option<&str> {
+        self.key_column
+            .and_then(|index| self.columns.get(index))
+            .map(String::as_str)
+    }
+
+    /// 키 열과 아이템 속성 열을 제외한, 표시되는 값 열의 이름을 순서대로 반환합니다.
+    pub fn value_columns(&self) -> impl Iterator<Item = &str> {
+        self.columns
+            .iter()
+            .enumerate()
+            .filter(|(index, name)| Some(*index) != self.key_column && !is_attribute(name))
+            .map(|(_, name)| name.as_str())
+    }
+}
+
+/// JSON 기반 [`ListBox`](super::ListBox)의 아이템
+///
+/// 여러 값 열을 가진 아이템의 모든 값을 보존하며, [`ListBoxItemInfo`]로 변환할 때에는 첫번째와 두번째 값이 `value1`, `value2`가 됩니다.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListBoxTableDataItem {
+    index: String,
+    key: String,
+    values: Vec<String>,
+    selected: bool,
+    enabled: bool,
+    title: String,
+}
+
+impl ListBoxTableDataItem {
+    /// 아이템의 인덱스를 반환합니다.
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /// 아이템의 키를 반환합니다.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 값 열의 값들을 순서대로 반환합니다.
+    pub fn values(&self) -> &[String] {
+        &self.values
+    }
+
+    /// 아이템의 선택 여부를 반환합니다.
+    pub fn selected(&self) -> bool {
+        self.selected
+    }
+
+    /// 아이템의 활성화 여부를 반환합니다.
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// 아이템의 제목을 반환합니다.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+}
+
+impl From<ListBoxTableDataItem> for ListBoxItemInfo {
+    fn from(item: ListBoxTableDataItem) -> Self {
+        let mut values = item.values.into_iter();
+        ListBoxItemInfo::Item {
+            index: item.index,
+            key: item.key,
+            value1: values.next().unwrap_or_default(),
+            value2: values.next().unwrap_or_default(),
+            selected: item.selected,
+            enabled: item.enabled,
+            title: item.title,
+        }
+    }
+}
+
+/// `item_table_data`나 `history_table_data` 문자열을 [`ListBoxTableDataItem`]의 목록으로 해석합니다.
+///
+/// 아이템 목록은 행의 배열이며, 각 행은 열 이름을 키로 하는 오브젝트이거나 열 정의의 순서를 따르는 배열입니다.
+/// `index`, `selected`, `enabled`, `title` 열은 값 열이 아닌 아이템의 속성으로 취급합니다.
+/// 열 정의가 없는 경우 오브젝트 행은 `key` 필드를 키로, 나머지 필드를 값으로 취급하며 배열 행은 첫번째 값을 키로 취급합니다.
+pub fn parse_table_data_items(
+    raw: &str,
+    definition: Option<&ListBoxTableDataDefinition>,
+) -> Result<Vec<ListBoxTableDataItem>, WebDynproError> {
+    let value = parse_table_json(raw)?;
+    let rows = value
+        .as_array()
+        .ok_or_else(|| invalid_table_data("table data"))?;
+    rows.iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let fields = match row {
+                Value::Object(object) => object
+                    .iter()
+                    .map(|(name, value)| (name.to_owned(), value_to_string(value)))
+                    .collect::<Vec<(String, String)>>(),
+                Value::Array(cells) => {
+                    let names = definition.map(ListBoxTableDataDefinition::columns);
+                    cells
+                        .iter()
+                        .enumerate()
+                        .map(|(col, value)| {
+                            let name = names
+                                .and_then(|names| names.get(col))
+                                .cloned()
+                                .unwrap_or_else(|| {
+                                    if col == 0 && definition.is_none() {
+                                        KEY_COLUMN.to_string()
+                                    } else {
+                                        col.to_string()
+                                    }
+                                });
+                            (name, value_to_string(value))
+                        })
+                        .collect()
+                }
+                _ => Err(invalid_table_data("row of table data"))?,
+            };
+            Ok(table_data_item(index, fields, definition))
+        })
+        .collect()
+}
+
+fn table_data_item(
+    index: usize,
+    fields: Vec<(String, String)>,
+    definition: Option<&ListBoxTableDataDefinition>,
+) -> ListBoxTableDataItem {
+    let field = |column: &str| {
+        fields
+            .iter()
+            .find(|(name, _)| name == column)
+            .map(|(_, value)| value.as_str())
+    };
+    let flag = |column: &str, default: bool| {
+        field(column).map_or(default, |value| {
+            matches!(value.to_ascii_lowercase().as_str(), "true" | "x" | "1")
+        })
+    };
+    let key_column = definition
+        .and_then(ListBoxTableDataDefinition::key_column)
+        .unwrap_or(KEY_COLUMN);
+    let values = match definition {
+        Some(definition) => definition
+            .value_columns()
+            .map(|column| field(column).unwrap_or_default().to_owned())
+            .collect(),
+        None => fields
+            .iter()
+            .filter(|(name, _)| name != KEY_COLUMN && !is_attribute(name))
+            .map(|(_, value)| value.to_owned())
+            .collect(),
+    };
+    ListBoxTableDataItem {
+        index: field(INDEX_COLUMN)
+            .map(str::to_owned)
+            .unwrap_or_else(|| index.to_string()),
+        key: field(key_column)
+            .map(str::to_owned)
+            .unwrap_or_else(|| index.to_string()),
+        values,
+        selected: flag(SELECTED_COLUMN, false),
+        enabled: flag(ENABLED_COLUMN, true),
+        title: field(TITLE_COLUMN).unwrap_or_default().to_owned(),
+    }
+}
+
+// 표준 JSON으로 먼저 해석하고, 실패하면 lsdata 형식의 JSON으로 해석합니다.
+fn parse_table_json(raw: &str) -> Result<Value, WebDynproError> {
+    serde_json::from_str(raw).or_else(|_| crate::element::utils::parse_lsdata(raw))
+}
+
+fn is_attribute(name: &str) -> bool {
+    [INDEX_COLUMN, SELECTED_COLUMN, ENABLED_COLUMN, TITLE_COLUMN].contains(&name)
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::String(str) => str.to_owned(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn invalid_table_data(content: &str) -> ElementError {
+    ElementError::InvalidContent {
+        element: "ListBox".to_string(),
+        content: content.to_string(),
+    }
+}
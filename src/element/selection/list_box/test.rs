@@ -0,0 +1,175 @@
+use super::item::ListBoxItemInfo;
+use super::table_data::{ListBoxTableDataDefinition, parse_table_data_items};
+use super::{ListBoxMultipleDef, ListBoxPopupDef};
+use crate::body::test::body;
+use crate::element::definition::ElementDefinition as _;
+use crate::element::parser::ElementParser;
+use crate::element::property::SuggestFilterCondition;
+
+#[test]
+fn table_data_with_definition() {
+    let definition = ListBoxTableDataDefinition::parse(
+        r#"[{"name":"CODE","key":true},"TEXT",{"name":"DESC"},"selected"]"#,
+    )
+    .unwrap();
+    assert_eq!(definition.key_column(), Some("CODE"));
+    assert_eq!(
+        definition.value_columns().collect::<Vec<_>>(),
+        vec!["TEXT", "DESC"]
+    );
+
+    let items = parse_table_data_items(
+        r#"[{"CODE":"01","TEXT":"Seoul","DESC":"KR","selected":"X"},["02","Busan","KR",false]]"#,
+        Some(&definition),
+    )
+    .unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].key(), "01");
+    assert_eq!(items[0].values(), ["Seoul", "KR"]);
+    assert!(items[0].selected());
+    assert_eq!(items[1].key(), "02");
+    assert_eq!(items[1].values(), ["Busan", "KR"]);
+    assert!(!items[1].selected());
+
+    let info = ListBoxItemInfo::from(items[1].clone());
+    assert!(matches!(
+        info,
+        ListBoxItemInfo::Item { key, value1, value2, index, .. }
+            if key == "02" && value1 == "Busan" && value2 == "KR" && index == "1"
+    ));
+}
+
+#[test]
+fn table_data_without_definition() {
+    let items = parse_table_data_items(
+        r#"[{"key":"A","text":"Alpha"},["B","Beta","Second"]]"#,
+        None,
+    )
+    .unwrap();
+    assert_eq!(items[0].key(), "A");
+    assert_eq!(items[0].values(), ["Alpha"]);
+    assert!(items[0].enabled());
+    assert_eq!(items[1].key(), "B");
+    assert_eq!(items[1].values(), ["Beta", "Second"]);
+}
+
+#[test]
+fn table_data_lsjson() {
+    let definition = ListBoxTableDataDefinition::parse("['KEY','VALUE1','VALUE2']").unwrap();
+    assert_eq!(definition.key_column(), Some("KEY"));
+    let items =
+        parse_table_data_items("[{KEY:'K1',VALUE1:'One',VALUE2:'1'}]", Some(&definition)).unwrap();
+    assert_eq!(items[0].key(), "K1");
+    assert_eq!(items[0].values(), ["One", "1"]);
+}
+
+#[test]
+fn table_data_rejects_unknown_shape() {
+    assert!(ListBoxTableDataDefinition::parse(r#"{"columns":["KEY","TEXT"]}"#).is_err());
+    assert!(parse_table_data_items(r#"{"rows":[["A","Alpha"]]}"#, None).is_err());
+}
+
+const LIST_BOX: ListBoxPopupDef = ListBoxPopupDef::new("LB");
+
+fn list_box_html(lsdata: &str) -> String {
+    format!(
+        r#"<div ct="LIB_P" id="LB" lsdata="{lsdata}"><div ct="LIB_I" id="LB-1" data-itemkey="A" data-itemvalue1="Apple" data-itemvalue2="Fruit"></div><div ct="LIB_I" id="LB-2" data-itemkey="C" data-itemvalue1="Carrot" data-itemvalue2="Vegetable"></div></div>"#
+    )
+}
+
+fn item_keys(items: impl Iterator<Item = ListBoxItemInfo>) -> Vec<String> {
+    items
+        .filter_map(|info| match info {
+            ListBoxItemInfo::Item { key, .. } => Some(key),
+            ListBoxItemInfo::ActionItem { .. } => None,
+        })
+        .collect()
+}
+
+fn suggestion_keys(
+    html: &str,
+    value: &str,
+    condition: Option<SuggestFilterCondition>,
+    prefix: bool,
+) -> Vec<String> {
+    let body = body(html);
+    let parser = ElementParser::new(&body);
+    let list_box = parser.element_from_def(&LIST_BOX).unwrap();
+    item_keys(
+        list_box
+            .list_box()
+            .suggestions(value, condition, prefix)
+            .unwrap()
+            .into_iter(),
+    )
+}
+
+#[test]
+fn suggestions_filter_on_client() {
+    let html = list_box_html("{}");
+    assert_eq!(suggestion_keys(&html, "RRO", None, false), vec!["C"]);
+    assert!(suggestion_keys(&html, "rro", None, true).is_empty());
+    assert_eq!(suggestion_keys(&html, "ap", None, true), vec!["A"]);
+    assert!(suggestion_keys(&html, "fruit", None, false).is_empty());
+    assert_eq!(
+        suggestion_keys(
+            &html,
+            "fruit",
+            Some(SuggestFilterCondition::Value1OrValue2),
+            false
+        ),
+        vec!["A"]
+    );
+}
+
+#[test]
+fn suggestions_filtered_by_server() {
+    let html = list_box_html("{21:true}");
+    assert_eq!(suggestion_keys(&html, "zzz", None, true), vec!["A", "C"]);
+}
+
+#[test]
+fn item_infos_with_item_table_data() {
+    // lsdata 안의 JSON 문자열은 따옴표가 `\x22`로 이스케이프되어 전달됩니다.
+    let body = body(&list_box_html(
+        r"{22:'[\x22KEY\x22,\x22VALUE1\x22,\x22VALUE2\x22]',23:'[[\x22A\x22,\x22Apple\x22,\x22Dup\x22],[\x22B\x22,\x22Banana\x22,\x22Fruit\x22]]'}",
+    ));
+    let parser = ElementParser::new(&body);
+    let list_box = parser.element_from_def(&LIST_BOX).unwrap();
+    let list_box = list_box.list_box();
+    assert_eq!(
+        item_keys(list_box.item_infos().unwrap()),
+        vec!["A", "C", "B"]
+    );
+    assert!(list_box.ensure_item_key("B").is_ok());
+    assert_eq!(list_box.items().count(), 2);
+}
+
+#[test]
+fn item_infos_skip_undecodable_table_data() {
+    let body = body(&list_box_html("{23:'not json'}"));
+    let parser = ElementParser::new(&body);
+    let list_box = parser.element_from_def(&LIST_BOX).unwrap();
+    let list_box = list_box.list_box();
+    assert!(list_box.table_data_items().is_err());
+    assert_eq!(item_keys(list_box.item_infos().unwrap()), vec!["A", "C"]);
+    assert!(list_box.ensure_item_key("A").is_ok());
+    assert!(list_box.ensure_item_key("B").is_err());
+}
+
+#[test]
+fn multi_select_keys() {
+    let body = body(
+        r#"<div ct="LIB_M" id="LB" lsdata="{}" lsevents="{'MultiSelect':[{},{}]}"><div ct="LIB_I" id="LB-1" data-itemkey="A"></div><div ct="LIB_I" id="LB-2" data-itemkey="C"></div><div ct="LIB_I" id="LB-3" data-itemkey="B,C"></div></div>"#,
+    );
+    let parser = ElementParser::new(&body);
+    let list_box = parser
+        .element_from_def(&ListBoxMultipleDef::new_dynamic("LB".to_string()))
+        .unwrap();
+    assert_eq!(
+        list_box.multi_select(&["A", "C"]).unwrap().serialize(),
+        "ListBox_MultiSelect~E002Id~E004LB~E005Keys~E004A~002CC~E003~E002~E003~E002~E003"
+    );
+    assert!(list_box.multi_select(&["A", "Z"]).is_err());
+    assert!(list_box.multi_select(&["B,C"]).is_err());
+}
@@ -4,8 +4,9 @@
 // The WdLsData derive only handles Deserialize. Additionally, client_infos() builds
 // custom UCF parameters that don't follow the standard wd_event pattern.
 
-use std::{borrow::Cow, cell::OnceCell, collections::HashMap};
+use std::{borrow::Cow, cell::OnceCell};
 
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 use crate::element::definition::ElementDefinition;
@@ -247,7 +248,7 @@ impl<'a> ClientInspector<'a> {
     /// 서버에 이 엘리먼트가 감시하는 클라이언트 값을 알리는 이벤트를 반환합니다.
     /// 데이터는 특수한 형태의 JSON-like 값으로, 현재는 rusaint에서 Serialization/Deserialization 을 지원하지 않습니다.
     pub fn notify(&self, data: &str) -> Result<Event, WebDynproError> {
-        let mut parameters: HashMap<String, String> = HashMap::new();
+        let mut parameters: IndexMap<String, String> = IndexMap::new();
 
         parameters.insert("Id".to_string(), self.id.clone().to_string());
         parameters.insert("Data".to_string(), data.to_string());
@@ -2,7 +2,9 @@
 // with no DOM representation. It has no element_ref (panics on access),
 // uses () as ElementLSData, and is only constructed programmatically.
 
-use std::{borrow::Cow, collections::HashMap};
+use std::borrow::Cow;
+
+use indexmap::IndexMap;
 
 use crate::{
     element::{ElementWrapper, definition::ElementDefinition},
@@ -158,7 +160,7 @@ impl Custom {
 
     /// 서버에 클라이언트 정보를 보내는 이벤트를 반환합니다.
     pub fn client_infos(&self, infos: CustomClientInfo) -> Event {
-        let mut parameters: HashMap<String, String> = HashMap::new();
+        let mut parameters: IndexMap<String, String> = IndexMap::new();
         let ucf_params = UcfParametersBuilder::default()
             .action(Some(UcfAction::Enqueue))
             .response(Some(UcfResponseData::Delta))
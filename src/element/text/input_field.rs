@@ -2,13 +2,19 @@ use std::{borrow::Cow, cell::OnceCell};
 
 use crate::{
     WdElement, WdLsData,
-    element::property::{
-        EmbeddingBehaviour, IMEMode, InputFieldTextStyle, InputFieldType, SemanticColor,
-        TabBehaviour, TableFieldDesign, Visibility,
+    element::{
+        ElementDefWrapper,
+        parser::ElementParser,
+        property::{
+            EmbeddingBehaviour, IMEMode, InputFieldTextStyle, InputFieldType, SemanticColor,
+            TabBehaviour, TableFieldDesign, Visibility,
+        },
+        selection::list_box::{ListBoxDefWrapper, item::ListBoxItemInfo},
     },
+    error::{BodyError, ElementError, WebDynproError},
+    wd_event,
 };
 
-// TODO: Implement additional events and data
 #[doc = "[`InputField`] 내부 데이터"]
 #[derive(WdLsData)]
 #[allow(unused)]
@@ -103,6 +109,107 @@ impl<'a> InputField<'a> {
         use crate::element::Element as _;
         self.element_ref().attr("value")
     }
+
+    /// 이 [`InputField`]의 입력 값 종류를 반환합니다.
+    pub fn input_field_type(&self) -> Option<&InputFieldType> {
+        use crate::element::Element as _;
+        self.lsdata().input_field_type()
+    }
+
+    /// 이 [`InputField`]가 읽기 전용인지 여부를 반환합니다.
+    pub fn readonly(&self) -> bool {
+        use crate::element::Element as _;
+        let element = self.element_ref().value();
+        element.attr("readonly").is_some()
+            || element
+                .attr("aria-readonly")
+                .is_some_and(|str| str == "true")
+    }
+
+    /// 이 [`InputField`]가 비활성화 상태인지 여부를 반환합니다.
+    pub fn disabled(&self) -> bool {
+        use crate::element::Element as _;
+        let element = self.element_ref().value();
+        element.attr("disabled").is_some()
+            || element
+                .attr("aria-disabled")
+                .is_some_and(|str| str == "true")
+    }
+
+    /// 이 [`InputField`]가 필수 입력 필드인지 여부를 반환합니다.
+    pub fn required(&self) -> bool {
+        use crate::element::Element as _;
+        self.element_ref()
+            .attr("aria-required")
+            .is_some_and(|str| str == "true")
+    }
+
+    /// 이 [`InputField`]가 올바르지 않은 상태인지 여부를 반환합니다.
+    pub fn invalid(&self) -> bool {
+        use crate::element::Element as _;
+        self.element_ref()
+            .attr("aria-invalid")
+            .is_some_and(|str| str == "true")
+    }
+
+    /// 입력 필드의 값을 변경하는 이벤트를 반환합니다.
+    #[wd_event(name = "Change", params(value: &str => "Value"))]
+    pub fn change(&self, value: &str) {}
+
+    /// 입력 필드에서 엔터를 누르는 이벤트를 반환합니다.
+    #[wd_event(name = "Enter")]
+    pub fn enter(&self) {}
+
+    /// 입력 필드의 값 도움말(F4)을 요청하는 이벤트를 반환합니다.
+    #[wd_event(name = "ValueHelpRequest")]
+    pub fn value_help_request(&self) {}
+
+    /// 입력 중인 값에 대한 추천 목록(type-ahead)을 서버에 요청하는 이벤트를 반환합니다.
+    #[wd_event(name = "Suggest", params(value: &str => "Value"))]
+    pub fn suggest(&self, value: &str) {}
+
+    /// 추천 목록을 표시하는 [`ListBox`](crate::element::selection::list_box::ListBox) 엘리먼트를 가져옵니다.
+    ///
+    /// 입력 필드의 `aria-controls` 혹은 `aria-owns` 속성이 가리키는 엘리먼트를 찾습니다.
+    pub fn suggest_list_box(
+        &self,
+        parser: &ElementParser,
+    ) -> Result<ListBoxDefWrapper, WebDynproError> {
+        use crate::element::Element as _;
+        let element = self.element_ref().value();
+        let listbox_id = element
+            .attr("aria-controls")
+            .or_else(|| element.attr("aria-owns"))
+            .and_then(|ids| ids.split_whitespace().next())
+            .ok_or(ElementError::NoSuchData {
+                element: self.id().to_string(),
+                field: "aria-controls".to_string(),
+            })?;
+        let selector = scraper::Selector::parse(format!(r#"[id="{listbox_id}"]"#).as_str())
+            .or(Err(ElementError::InvalidId(listbox_id.to_owned())))?;
+        let elem = parser
+            .document()
+            .select(&selector)
+            .next()
+            .ok_or(BodyError::NoSuchElement(listbox_id.to_owned()))?;
+        Ok(
+            ListBoxDefWrapper::from_def(ElementDefWrapper::from_ref(elem)?)
+                .ok_or(BodyError::NoSuchElement(listbox_id.to_owned()))?,
+        )
+    }
+
+    /// 입력한 값에 대한 추천 아이템을 반환합니다.
+    ///
+    /// 서버에서 이미 필터링한 목록이라면 그대로 반환하고, 그렇지 않다면 `value1`이 입력한 값을 포함하는 아이템만 반환합니다.
+    pub fn suggestions(
+        &self,
+        value: &str,
+        parser: &ElementParser,
+    ) -> Result<Vec<ListBoxItemInfo>, WebDynproError> {
+        self.suggest_list_box(parser)?
+            .value(parser)?
+            .suggestions(value, None, false)
+    }
 }
 
 impl std::fmt::Display for InputField<'_> {
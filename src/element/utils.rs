@@ -1,9 +1,9 @@
 use crate::element::{ElementWrapper, EventParameterMap, normalize_lsjson};
 use crate::error::{BodyError, ElementError, WebDynproError};
 use crate::event::ucf_parameters::UcfParameters;
+use indexmap::IndexMap;
 use scraper::ElementRef;
 use serde_json::{Map, Value};
-use std::collections::HashMap;
 
 /// 엘리먼트의 lsdata 속성을 파싱합니다.
 pub(super) fn parse_lsdata(raw_lsdata: &str) -> Result<Value, WebDynproError> {
@@ -20,7 +20,7 @@ pub(super) fn parse_lsevents(raw_lsevents: &str) -> Result<EventParameterMap, We
             "Cannot deserialize lsevents field".to_string(),
         )))?
         .to_owned();
-    type EventParameters = (String, (UcfParameters, HashMap<String, String>));
+    type EventParameters = (String, (UcfParameters, IndexMap<String, String>));
     Ok(json
         .into_iter()
         .flat_map(|(key, value)| -> Result<EventParameters, BodyError> {
@@ -50,7 +50,7 @@ pub(super) fn parse_lsevents(raw_lsevents: &str) -> Result<EventParameterMap, We
             let custom_map = custom
                 .iter_mut()
                 .map(|(key, value)| (key.to_owned(), value.to_string()))
-                .collect::<HashMap<String, String>>();
+                .collect::<IndexMap<String, String>>();
             Ok((key, (ucf, custom_map)))
         })
         .collect::<EventParameterMap>())
@@ -77,3 +77,22 @@ pub(super) fn children_element(root: ElementRef) -> Vec<ElementWrapper> {
         .filter_map(|eref| ElementWrapper::from_ref(eref).ok())
         .collect()
 }
+
+/// 엘리먼트의 인라인 스타일에서 주어진 속성의 값을 반환합니다.
+pub(super) fn style_value<'a>(eref: &ElementRef<'a>, property: &str) -> Option<&'a str> {
+    eref.value().attr("style")?.split(';').find_map(|decl| {
+        let (name, value) = decl.split_once(':')?;
+        name.trim()
+            .eq_ignore_ascii_case(property)
+            .then_some(value.trim())
+    })
+}
+
+/// 엘리먼트가 `aria-hidden` 속성이나 인라인 스타일(`display:none`, `visibility:hidden`)로 숨겨져 있다면 참을 반환합니다.
+pub(super) fn is_hidden(eref: &ElementRef) -> bool {
+    eref.value()
+        .attr("aria-hidden")
+        .is_some_and(|value| value == "true")
+        || style_value(eref, "display").is_some_and(|value| value.eq_ignore_ascii_case("none"))
+        || style_value(eref, "visibility").is_some_and(|value| value.eq_ignore_ascii_case("hidden"))
+}
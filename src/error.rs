@@ -151,6 +151,33 @@ pub enum ElementError {
     /// LSData 오브젝트를 파싱할 수 없음
     #[error("Failed parse lsdata json-like object")]
     ParseLSData(#[from] serde_json::Error),
+    /// 테이블 셀의 값을 원하는 형으로 변환할 수 없음
+    #[error("Cannot convert table cell at row {row:?}, column {col} ({text:?}): {message}")]
+    InvalidTableCell {
+        row: Option<u32>,
+        col: usize,
+        text: String,
+        message: String,
+    },
+    /// 테이블의 내용을 serde로 역직렬화할 수 없음
+    #[error("Cannot deserialize table: {0}")]
+    DeserializeTable(String),
+    /// 테이블의 내용을 내보낼 수 없음
+    #[error("Cannot export table: {0}")]
+    ExportTable(#[from] std::io::Error),
+    /// 주어진 텍스트와 일치하는 선택지를 찾을 수 없음
+    #[error("Cannot find option {text:?} in element: {element} (available options: {})", .available.join(", "))]
+    NoSuchOption {
+        element: String,
+        text: String,
+        available: Vec<String>,
+    },
+}
+
+impl serde::de::Error for ElementError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        ElementError::DeserializeTable(msg.to_string())
+    }
 }
 
 /// 이벤트 문자열을 일반 문자열로 변환할 떄 발생하는 오류의 이늄
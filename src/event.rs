@@ -2,8 +2,9 @@ use self::ucf_parameters::UcfParameters;
 use derive_builder::Builder;
 
 use super::error::EventStrUnescapeError;
+use indexmap::IndexMap;
 use std::fmt::Display;
-use std::{borrow::Cow, collections::HashMap, num::ParseIntError};
+use std::{borrow::Cow, num::ParseIntError};
 
 const EVENT_SPECTATOR: &str = "~E001";
 const EVENT_DATA_START: &str = "~E002";
@@ -83,45 +84,41 @@ pub fn unescape_str(text: &str) -> Result<Cow<'_, str>, EventStrUnescapeError> {
 }
 
 /// 엘리먼트에서 전송하는 이벤트
+///
+/// 파라메터는 삽입된 순서를 유지하며, 직렬화 시에도 해당 순서대로 출력됩니다.
 #[allow(missing_docs)]
 #[derive(Builder, Clone, Debug)]
 pub struct Event {
     event: String,
     control: String,
     #[builder(default)]
-    parameters: HashMap<String, String>,
+    parameters: IndexMap<String, String>,
     #[builder(default)]
     ucf_parameters: UcfParameters,
     #[builder(default)]
-    custom_parameters: HashMap<String, String>,
+    custom_parameters: IndexMap<String, String>,
+}
+
+fn serialize_parameters(owned: &mut String, parameters: &IndexMap<String, String>) {
+    owned.push_str(EVENT_DATA_START);
+    let mut params = parameters.iter().peekable();
+    while let Some((key, val)) = params.next() {
+        owned.push_str(key);
+        owned.push_str(EVENT_DATA_COLON);
+        owned.push_str(escape_str(val).as_str());
+        if params.peek().is_some() {
+            owned.push_str(EVENT_DATA_COMMA);
+        }
+    }
+    owned.push_str(EVENT_DATA_END);
 }
 
 impl Display for Event {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut owned = format!("{}_{}", &self.control, &self.event).to_owned();
-        owned.push_str(EVENT_DATA_START);
-        let mut params = self.parameters.iter().peekable();
-        while let Some((key, val)) = params.next() {
-            owned.push_str(key);
-            owned.push_str(EVENT_DATA_COLON);
-            owned.push_str(escape_str(val).as_str());
-            if params.peek().is_some() {
-                owned.push_str(EVENT_DATA_COMMA);
-            }
-        }
-        owned.push_str(EVENT_DATA_END);
+        serialize_parameters(&mut owned, &self.parameters);
         owned.push_str(&self.ucf_parameters.serialize());
-        owned.push_str(EVENT_DATA_START);
-        let custom_params = self.custom_parameters.iter().peekable();
-        for (key, val) in custom_params {
-            owned.push_str(key);
-            owned.push_str(EVENT_DATA_COLON);
-            owned.push_str(escape_str(val).as_str());
-            if params.peek().is_some() {
-                owned.push_str(EVENT_DATA_COMMA);
-            }
-        }
-        owned.push_str(EVENT_DATA_END);
+        serialize_parameters(&mut owned, &self.custom_parameters);
         write!(f, "{owned}")
     }
 }
@@ -141,6 +138,48 @@ impl Event {
     pub fn is_submitable(&self) -> bool {
         self.ucf_parameters.is_submitable()
     }
+
+    /// 이 이벤트가 큐에 한번만 저장될 수 있다면 참을 반환합니다.
+    pub fn is_single(&self) -> bool {
+        self.ucf_parameters.is_single()
+    }
+
+    /// 이벤트를 발생시킨 컨트롤의 이름을 반환합니다.
+    pub fn control(&self) -> &str {
+        &self.control
+    }
+
+    /// 이벤트의 이름을 반환합니다.
+    pub fn event(&self) -> &str {
+        &self.event
+    }
+
+    /// 이벤트의 파라메터를 반환합니다.
+    pub fn parameters(&self) -> &IndexMap<String, String> {
+        &self.parameters
+    }
+
+    /// 이벤트의 [`UcfParameters`]를 반환합니다.
+    pub fn ucf_parameters(&self) -> &UcfParameters {
+        &self.ucf_parameters
+    }
+
+    /// 이벤트의 커스텀 파라메터를 반환합니다.
+    pub fn custom_parameters(&self) -> &IndexMap<String, String> {
+        &self.custom_parameters
+    }
+
+    /// 이벤트를 발생시킨 엘리먼트의 Id를 반환합니다.
+    pub fn element_id(&self) -> Option<&str> {
+        self.parameters.get("Id").map(String::as_str)
+    }
+
+    /// 두 이벤트가 같은 엘리먼트의 같은 종류의 이벤트라면 참을 반환합니다.
+    pub fn is_same_kind(&self, other: &Event) -> bool {
+        self.control == other.control
+            && self.event == other.event
+            && self.element_id() == other.element_id()
+    }
 }
 
 /// 이벤트 큐를 관리하는 모듈
@@ -1,24 +1,171 @@
 use crate::{
     error::ClientError,
-    event::{EventBuilder, EventBuilderError, ucf_parameters::UcfParameters},
+    event::{
+        EventBuilder, EventBuilderError,
+        ucf_parameters::{UcfParameters, UcfResponseData, UcfTransportMethod},
+    },
 };
 
 use super::{EVENT_SPECTATOR, Event};
-use std::collections::{HashMap, LinkedList};
+use derive_builder::Builder;
+use indexmap::IndexMap;
+use std::collections::LinkedList;
 
 /// 이벤트가 추가된 후, [`EventQueue`]가 처리되어야 할 지를 나타내는 enum입니다.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum EnqueueEventResult {
     /// [`EventQueue`]가 처리되어야 함을 나타냅니다. [`EventQueue::serialize_and_clear()`] 함수로 큐를 처리할 수 있습니다.
-    ShouldProcess,
+    ///
+    /// 큐를 어떻게 전송해야 하는지는 [`FlushPolicy`]로 전달됩니다.
+    ShouldProcess(FlushPolicy),
     /// [`EventQueue`]에 이벤트가 추가되었고, 별도의 작업이 필요하지 않음을 나타냅니다.
     Enqueued,
 }
 
+impl EnqueueEventResult {
+    /// [`EventQueue`]가 처리되어야 한다면 참을 반환합니다.
+    pub fn should_process(&self) -> bool {
+        matches!(self, Self::ShouldProcess(_))
+    }
+
+    /// [`EventQueue`]가 처리되어야 한다면 큐의 [`FlushPolicy`]를 반환합니다.
+    pub fn flush_policy(&self) -> Option<&FlushPolicy> {
+        match self {
+            Self::ShouldProcess(policy) => Some(policy),
+            Self::Enqueued => None,
+        }
+    }
+}
+
+/// 큐에 저장된 이벤트들의 [`UcfParameters`]로부터 결정된 이벤트 큐 전송 방식
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FlushPolicy {
+    asynchronous: bool,
+    delayed: bool,
+    sync_execution: bool,
+    transport: Option<UcfTransportMethod>,
+    response: Option<UcfResponseData>,
+}
+
+impl FlushPolicy {
+    fn from_ucf_parameters(ucf: &UcfParameters) -> FlushPolicy {
+        FlushPolicy {
+            asynchronous: ucf.is_async(),
+            delayed: ucf.is_delayed(),
+            sync_execution: ucf.sync_execution().unwrap_or(false),
+            transport: ucf.transport(),
+            response: ucf.response(),
+        }
+    }
+
+    /// 아직 전송되지 않은 정책에 새로운 정책을 합칩니다.
+    /// 두 정책 중 하나라도 동기 전송이나 즉시 전송, 전체 전송을 요구하면 이를 따릅니다.
+    fn merge(self, other: FlushPolicy) -> FlushPolicy {
+        let transport = match (self.transport, other.transport) {
+            (Some(UcfTransportMethod::Full), _) | (_, Some(UcfTransportMethod::Full)) => {
+                Some(UcfTransportMethod::Full)
+            }
+            (transport, None) => transport,
+            (_, transport) => transport,
+        };
+        FlushPolicy {
+            asynchronous: self.asynchronous && other.asynchronous,
+            delayed: self.delayed && other.delayed,
+            sync_execution: self.sync_execution || other.sync_execution,
+            transport,
+            response: other.response.or(self.response),
+        }
+    }
+
+    /// 큐가 비동기(`submit_async`)로 전송되어야 한다면 참을 반환합니다.
+    pub fn is_async(&self) -> bool {
+        self.asynchronous
+    }
+
+    /// 큐의 전송이 지연(`Delay: full`)되어야 한다면 참을 반환합니다.
+    /// 브라우저 클라이언트는 지연된 전송 전에 다른 이벤트가 발생하면 해당 이벤트와 함께 큐를 전송합니다.
+    pub fn is_delayed(&self) -> bool {
+        self.delayed
+    }
+
+    /// 큐의 전송이 다른 요청과 동기적으로 실행(`SyncExecution`)되어야 한다면 참을 반환합니다.
+    pub fn is_sync_execution(&self) -> bool {
+        self.sync_execution
+    }
+
+    /// 큐 전송 시 폼 데이터 일부만 전송(`TransportMethod: partial`)할 수 있다면 참을 반환합니다.
+    pub fn is_partial_transport(&self) -> bool {
+        matches!(self.transport, Some(UcfTransportMethod::Partial))
+    }
+
+    /// 요청된 [`UcfTransportMethod`]를 반환합니다.
+    pub fn transport(&self) -> Option<UcfTransportMethod> {
+        self.transport
+    }
+
+    /// 요청된 [`UcfResponseData`]를 반환합니다.
+    pub fn response(&self) -> Option<UcfResponseData> {
+        self.response
+    }
+}
+
+/// 이벤트 큐 전송 시 마지막에 추가되는 `Form_Request` 이벤트의 파라메터
+///
+/// `focused_id`가 지정되지 않으면 큐에 마지막으로 추가된 이벤트의 엘리먼트 Id를 포커스 정보로 사용합니다.
+#[allow(missing_docs)]
+#[derive(Builder, Clone, Debug, Default)]
+#[builder(default, setter(into))]
+pub struct FormRequest {
+    is_async: bool,
+    #[builder(setter(strip_option))]
+    focused_id: Option<String>,
+    hash: String,
+    dom_changed: bool,
+    is_dirty: bool,
+}
+
+impl FormRequest {
+    /// `Async` 파라메터 값을 반환합니다.
+    pub fn is_async(&self) -> bool {
+        self.is_async
+    }
+
+    /// 포커스된 엘리먼트의 Id를 반환합니다.
+    pub fn focused_id(&self) -> Option<&str> {
+        self.focused_id.as_deref()
+    }
+
+    /// `Hash` 파라메터 값을 반환합니다.
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    /// `DomChanged` 파라메터 값을 반환합니다.
+    pub fn dom_changed(&self) -> bool {
+        self.dom_changed
+    }
+
+    /// `IsDirty` 파라메터 값을 반환합니다.
+    pub fn is_dirty(&self) -> bool {
+        self.is_dirty
+    }
+
+    fn focus_info(&self, fallback_id: Option<&str>) -> String {
+        match self.focused_id.as_deref().or(fallback_id) {
+            Some(id) => format!("@{}", serde_json::json!({ "sFocussedId": id })),
+            None => String::new(),
+        }
+    }
+}
+
+const NON_FOCUSABLE_CONTROLS: [&str; 4] =
+    ["ClientInspector", "LoadingPlaceHolder", "Custom", "Form"];
+
 /// 이벤트 큐를 관리하는 구조체
 #[derive(Debug)]
 pub struct EventQueue {
     queue: LinkedList<Event>,
-    should_process: bool,
+    transaction: Option<LinkedList<Event>>,
 }
 
 impl EventQueue {
@@ -26,21 +173,48 @@ impl EventQueue {
     pub fn new() -> EventQueue {
         EventQueue {
             queue: LinkedList::new(),
-            should_process: false,
+            transaction: None,
         }
     }
 
     /// 이벤트 큐의 내용을 Form 이벤트와 함께 직렬화하고 큐를 비웁니다.
+    ///
+    /// Form 이벤트의 포커스 정보는 큐에 마지막으로 추가된 이벤트의 엘리먼트를 가리킵니다.
     pub fn serialize_and_clear_with_form_event(&mut self) -> Result<String, ClientError> {
-        let form_req = create_form_request_event(false, "", "", false, false).or(Err(
-            ClientError::NoSuchForm("sap.client.SsrClient.form".to_string()),
-        ))?;
-        self.add(form_req.to_owned());
+        self.serialize_and_clear_with_form_request(&FormRequest::default())
+    }
+
+    /// 이벤트 큐의 내용을 주어진 [`FormRequest`]로 생성한 Form 이벤트와 함께 직렬화하고 큐를 비웁니다.
+    pub fn serialize_and_clear_with_form_request(
+        &mut self,
+        form_request: &FormRequest,
+    ) -> Result<String, ClientError> {
+        let focus_info = form_request.focus_info(self.last_element_id());
+        let form_req = create_form_request_event(
+            form_request.is_async,
+            &focus_info,
+            &form_request.hash,
+            form_request.dom_changed,
+            form_request.is_dirty,
+        )
+        .or(Err(ClientError::NoSuchForm(
+            "sap.client.SsrClient.form".to_string(),
+        )))?;
+        self.add(form_req);
         Ok(self.serialize_and_clear())
     }
 
-    /// 이벤트 큐의 내용을 직렬화하고 큐를 비웁니다.
-    pub fn serialize_and_clear(&mut self) -> String {
+    // 포커스 대상이 될 수 없는 시스템 엘리먼트의 이벤트는 건너뜁니다.
+    fn last_element_id(&self) -> Option<&str> {
+        self.queue
+            .iter()
+            .rev()
+            .filter(|event| !NON_FOCUSABLE_CONTROLS.contains(&event.control()))
+            .find_map(|event| event.element_id())
+    }
+
+    /// 이벤트 큐의 내용을 큐를 비우지 않고 직렬화합니다.
+    pub fn serialize(&self) -> String {
         let mut owned = "".to_owned();
         let events = &self.queue;
         for (idx, event) in events.iter().enumerate() {
@@ -49,20 +223,37 @@ impl EventQueue {
                 owned.push_str(EVENT_SPECTATOR);
             }
         }
-        let _ = &self.queue.clear();
+        owned
+    }
+
+    /// 이벤트 큐의 내용을 직렬화하고 큐를 비웁니다.
+    ///
+    /// 진행 중인 트랜잭션이 있다면 트랜잭션 중 추가된 이벤트도 함께 직렬화되고 트랜잭션은 커밋된 것으로 취급되어 종료됩니다.
+    /// 따라서 이후의 [`EventQueue::rollback_transaction()`]은 아무 작업도 하지 않으며, 전송 전에 트랜잭션을 취소하려면 이 함수보다 먼저 호출해야 합니다.
+    pub fn serialize_and_clear(&mut self) -> String {
+        let owned = self.serialize();
+        self.queue.clear();
+        self.transaction = None;
         owned
     }
 
     /// 이벤트를 큐에 추가합니다.
+    ///
+    /// 이벤트의 `EnqueueCardinality`가 `single`이라면 큐에 있는 같은 엘리먼트의 같은 이벤트를 제거한 후 추가합니다.
+    /// 큐를 전송해야 하는 경우 큐에 저장된 이벤트들로부터 결정된 [`FlushPolicy`]를 함께 반환합니다.
+    /// 트랜잭션이 진행 중이라면 [`EventQueue::commit_transaction()`]을 호출하기 전까지 항상 [`EnqueueEventResult::Enqueued`]를 반환합니다.
     pub fn add(&mut self, evt: Event) -> EnqueueEventResult {
-        if !evt.is_enqueable() && evt.is_submitable() {
-            self.should_process = true;
+        if evt.is_single() {
+            self.queue = std::mem::take(&mut self.queue)
+                .into_iter()
+                .filter(|queued| !queued.is_same_kind(&evt))
+                .collect();
         }
         self.queue.push_back(evt);
-        if self.should_process {
-            EnqueueEventResult::ShouldProcess
-        } else {
+        if self.is_in_transaction() {
             EnqueueEventResult::Enqueued
+        } else {
+            self.enqueue_result()
         }
     }
 
@@ -70,6 +261,74 @@ impl EventQueue {
     pub fn remove(&mut self) -> Option<Event> {
         self.queue.pop_front()
     }
+
+    /// 주어진 조건을 만족하는 이벤트들을 큐에서 제거하고 반환합니다.
+    pub fn remove_where(&mut self, mut predicate: impl FnMut(&Event) -> bool) -> Vec<Event> {
+        let (removed, remained): (LinkedList<Event>, LinkedList<Event>) =
+            std::mem::take(&mut self.queue)
+                .into_iter()
+                .partition(|event| predicate(event));
+        self.queue = remained;
+        removed.into_iter().collect()
+    }
+
+    /// 큐에 저장된 이벤트들을 순서대로 반환합니다.
+    pub fn events(&self) -> impl Iterator<Item = &Event> {
+        self.queue.iter()
+    }
+
+    /// 큐에 저장된 이벤트의 개수를 반환합니다.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// 큐가 비어있다면 참을 반환합니다.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// 큐에 저장된 이벤트들로부터 결정된 [`FlushPolicy`]를 반환합니다.
+    /// 큐에 즉시 전송해야 하는 이벤트가 없다면 `None`을 반환합니다.
+    pub fn flush_policy(&self) -> Option<FlushPolicy> {
+        self.queue
+            .iter()
+            .filter(|event| !event.is_enqueable() && event.is_submitable())
+            .map(|event| FlushPolicy::from_ucf_parameters(event.ucf_parameters()))
+            .reduce(FlushPolicy::merge)
+    }
+
+    fn enqueue_result(&self) -> EnqueueEventResult {
+        match self.flush_policy() {
+            Some(policy) => EnqueueEventResult::ShouldProcess(policy),
+            None => EnqueueEventResult::Enqueued,
+        }
+    }
+
+    /// 트랜잭션을 시작합니다. 트랜잭션 중 추가된 이벤트들은 커밋되기 전까지 전송이 필요하다고 판단되지 않습니다.
+    /// 이미 트랜잭션이 진행 중이라면 아무 작업도 하지 않습니다.
+    pub fn begin_transaction(&mut self) {
+        if self.transaction.is_none() {
+            self.transaction = Some(self.queue.clone());
+        }
+    }
+
+    /// 트랜잭션이 진행 중이라면 참을 반환합니다.
+    pub fn is_in_transaction(&self) -> bool {
+        self.transaction.is_some()
+    }
+
+    /// 트랜잭션을 종료하고, 트랜잭션 중 추가된 이벤트를 포함한 큐가 처리되어야 할 지를 반환합니다.
+    pub fn commit_transaction(&mut self) -> EnqueueEventResult {
+        self.transaction = None;
+        self.enqueue_result()
+    }
+
+    /// 트랜잭션을 취소하고 큐를 트랜잭션 시작 전의 상태로 되돌립니다.
+    pub fn rollback_transaction(&mut self) {
+        if let Some(snapshot) = self.transaction.take() {
+            self.queue = snapshot;
+        }
+    }
 }
 
 impl Default for EventQueue {
@@ -85,35 +344,38 @@ fn create_form_request_event(
     dom_changed: bool,
     is_dirty: bool,
 ) -> Result<Event, EventBuilderError> {
-    let mut form_parameters: HashMap<String, String> = HashMap::new();
+    let mut form_parameters: IndexMap<String, String> = IndexMap::new();
+    form_parameters.insert("FocusInfo".to_string(), focus_info.to_string());
     form_parameters.insert("Id".to_string(), "sap.client.SsrClient.form".to_string());
     form_parameters.insert("Async".to_string(), is_async.to_string());
-    form_parameters.insert("FocusInfo".to_string(), focus_info.to_string());
     form_parameters.insert("Hash".to_string(), hash.to_string());
-    form_parameters.insert("DomChanged".to_string(), dom_changed.to_string());
     form_parameters.insert("IsDirty".to_string(), is_dirty.to_string());
+    form_parameters.insert("DomChanged".to_string(), dom_changed.to_string());
     EventBuilder::default()
         .control("Form".to_string())
         .event("Request".to_string())
         .parameters(form_parameters)
         .ucf_parameters(UcfParameters::default())
-        .custom_parameters(HashMap::new())
+        .custom_parameters(IndexMap::new())
         .build()
 }
 
 #[cfg(test)]
 mod test {
-    use std::collections::HashMap;
+    use indexmap::IndexMap;
 
     use crate::event::{
         EventBuilder,
-        event_queue::EventQueue,
-        ucf_parameters::{UcfAction, UcfParametersBuilder, UcfResponseData},
+        event_queue::{EnqueueEventResult, EventQueue, FormRequestBuilder},
+        ucf_parameters::{
+            UcfAction, UcfCardinality, UcfDelay, UcfParametersBuilder, UcfResponseData,
+            UcfTransportMethod,
+        },
     };
 
     #[test]
     fn event_queue_serialize() {
-        let mut parameters = HashMap::new();
+        let mut parameters = IndexMap::new();
         parameters.insert("Id".to_string(), "WD0213".to_string());
         let ucf_params = UcfParametersBuilder::default()
             .response(Some(UcfResponseData::Delta))
@@ -127,16 +389,16 @@ mod test {
             .ucf_parameters(ucf_params)
             .build()
             .unwrap();
-        let mut parameters_two = HashMap::new();
-        parameters_two.insert("Id".to_string(), "sap.client.SsrClient.form".to_string());
-        parameters_two.insert("Async".to_string(), "false".to_string());
+        let mut parameters_two = IndexMap::new();
         parameters_two.insert(
             "FocusInfo".to_string(),
             "@{\"sFocussedId\":\"WD0213\"}".to_string(),
         );
+        parameters_two.insert("Id".to_string(), "sap.client.SsrClient.form".to_string());
+        parameters_two.insert("Async".to_string(), "false".to_string());
         parameters_two.insert("Hash".to_string(), "".to_string());
-        parameters_two.insert("DomChanged".to_string(), "false".to_string());
         parameters_two.insert("IsDirty".to_string(), "false".to_string());
+        parameters_two.insert("DomChanged".to_string(), "false".to_string());
         let ucf_params_two = UcfParametersBuilder::default()
             .response(Some(UcfResponseData::Delta))
             .build()
@@ -151,6 +413,229 @@ mod test {
         let mut queue = EventQueue::new();
         queue.add(event);
         queue.add(event_two);
-        assert_eq!(queue.serialize_and_clear().len(), "Button_Press~E002Id~E004WD0213~E003~E002ClientAction~E004submit~E005ResponseData~E004delta~E003~E002~E003~E001Form_Request~E002FocusInfo~E004~0040~007B~0022sFocussedId~0022~003A~0022WD0213~0022~007D~E005Id~E004sap.client.SsrClient.form~E005Async~E004false~E005Hash~E004~E005IsDirty~E004false~E005DomChanged~E004false~E003~E002ResponseData~E004delta~E003~E002~E003".len());
+        assert_eq!(
+            queue.serialize_and_clear(),
+            "Button_Press~E002Id~E004WD0213~E003~E002ClientAction~E004submit~E005ResponseData~E004delta~E003~E002~E003~E001Form_Request~E002FocusInfo~E004~0040~007B~0022sFocussedId~0022~003A~0022WD0213~0022~007D~E005Id~E004sap.client.SsrClient.form~E005Async~E004false~E005Hash~E004~E005IsDirty~E004false~E005DomChanged~E004false~E003~E002ResponseData~E004delta~E003~E002~E003"
+        );
+    }
+
+    #[test]
+    fn event_serialize_custom_parameters() {
+        let mut parameters = IndexMap::new();
+        parameters.insert("Id".to_string(), "WD01".to_string());
+        let mut custom_parameters = IndexMap::new();
+        custom_parameters.insert("Zeta".to_string(), "1".to_string());
+        custom_parameters.insert("Alpha".to_string(), "2".to_string());
+        let event = EventBuilder::default()
+            .control("Custom".to_owned())
+            .event("Test".to_owned())
+            .parameters(parameters)
+            .custom_parameters(custom_parameters)
+            .build()
+            .unwrap();
+        assert_eq!(
+            event.serialize(),
+            "Custom_Test~E002Id~E004WD01~E003~E002~E003~E002Zeta~E0041~E005Alpha~E0042~E003"
+        );
+    }
+
+    fn change_event(id: &str, value: &str, cardinality: UcfCardinality) -> crate::event::Event {
+        let mut parameters = IndexMap::new();
+        parameters.insert("Id".to_string(), id.to_string());
+        parameters.insert("Value".to_string(), value.to_string());
+        let ucf_params = UcfParametersBuilder::default()
+            .action(Some(UcfAction::Enqueue))
+            .cardinality(Some(cardinality))
+            .build()
+            .unwrap();
+        EventBuilder::default()
+            .control("InputField".to_owned())
+            .event("Change".to_owned())
+            .parameters(parameters)
+            .ucf_parameters(ucf_params)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn event_queue_single_cardinality() {
+        let mut queue = EventQueue::new();
+        queue.add(change_event("WD01", "a", UcfCardinality::Single));
+        queue.add(change_event("WD02", "b", UcfCardinality::Single));
+        queue.add(change_event("WD01", "c", UcfCardinality::Single));
+        let serialized = queue.serialize_and_clear();
+        assert_eq!(
+            serialized,
+            "InputField_Change~E002Id~E004WD02~E005Value~E004b~E003~E002ClientAction~E004enqueue~E005EnqueueCardinality~E004single~E003~E002~E003~E001InputField_Change~E002Id~E004WD01~E005Value~E004c~E003~E002ClientAction~E004enqueue~E005EnqueueCardinality~E004single~E003~E002~E003"
+        );
+    }
+
+    #[test]
+    fn event_queue_multiple_cardinality() {
+        let mut queue = EventQueue::new();
+        queue.add(change_event("WD01", "a", UcfCardinality::Multiple));
+        queue.add(change_event("WD01", "b", UcfCardinality::Multiple));
+        assert_eq!(queue.serialize_and_clear().matches("~E001").count(), 1);
+    }
+
+    fn submit_event(
+        id: &str,
+        action: UcfAction,
+        delay: Option<UcfDelay>,
+        transport: Option<UcfTransportMethod>,
+    ) -> crate::event::Event {
+        let mut parameters = IndexMap::new();
+        parameters.insert("Id".to_string(), id.to_string());
+        let ucf_params = UcfParametersBuilder::default()
+            .action(Some(action))
+            .delay(delay)
+            .transport(transport)
+            .response(Some(UcfResponseData::Delta))
+            .build()
+            .unwrap();
+        EventBuilder::default()
+            .control("Button".to_owned())
+            .event("Press".to_owned())
+            .parameters(parameters)
+            .ucf_parameters(ucf_params)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn event_queue_flush_policy() {
+        let mut queue = EventQueue::new();
+        assert_eq!(
+            queue.add(change_event("WD01", "a", UcfCardinality::Single)),
+            EnqueueEventResult::Enqueued
+        );
+        let result = queue.add(submit_event(
+            "WD02",
+            UcfAction::SubmitAsync,
+            Some(UcfDelay::Full),
+            Some(UcfTransportMethod::Partial),
+        ));
+        let policy = result.flush_policy().unwrap();
+        assert!(policy.is_async());
+        assert!(policy.is_delayed());
+        assert!(policy.is_partial_transport());
+        assert_eq!(policy.response(), Some(UcfResponseData::Delta));
+
+        let result = queue.add(submit_event(
+            "WD03",
+            UcfAction::Submit,
+            None,
+            Some(UcfTransportMethod::Full),
+        ));
+        let policy = result.flush_policy().unwrap();
+        assert!(!policy.is_async());
+        assert!(!policy.is_delayed());
+        assert!(!policy.is_partial_transport());
+
+        queue.serialize_and_clear();
+        assert!(
+            !queue
+                .add(change_event("WD01", "b", UcfCardinality::Single))
+                .should_process()
+        );
+    }
+
+    #[test]
+    fn event_queue_form_request_focus() {
+        let mut queue = EventQueue::new();
+        queue.add(change_event("WD01", "a", UcfCardinality::Multiple));
+        queue.add(submit_event("WD0213", UcfAction::Submit, None, None));
+        assert!(
+            queue
+                .serialize_and_clear_with_form_event()
+                .unwrap()
+                .ends_with("Form_Request~E002FocusInfo~E004~0040~007B~0022sFocussedId~0022~003A~0022WD0213~0022~007D~E005Id~E004sap.client.SsrClient.form~E005Async~E004false~E005Hash~E004~E005IsDirty~E004false~E005DomChanged~E004false~E003~E002~E003~E002~E003")
+        );
+
+        let form_request = FormRequestBuilder::default()
+            .focused_id("WD01")
+            .hash("hash")
+            .is_dirty(true)
+            .build()
+            .unwrap();
+        queue.add(submit_event("WD0213", UcfAction::Submit, None, None));
+        assert!(
+            queue
+                .serialize_and_clear_with_form_request(&form_request)
+                .unwrap()
+                .ends_with("Form_Request~E002FocusInfo~E004~0040~007B~0022sFocussedId~0022~003A~0022WD01~0022~007D~E005Id~E004sap.client.SsrClient.form~E005Async~E004false~E005Hash~E004hash~E005IsDirty~E004true~E005DomChanged~E004false~E003~E002~E003~E002~E003")
+        );
+
+        assert!(
+            queue
+                .serialize_and_clear_with_form_event()
+                .unwrap()
+                .contains("FocusInfo~E004~E005")
+        );
+    }
+
+    #[test]
+    fn event_queue_form_request_focus_skips_system_events() {
+        let mut queue = EventQueue::new();
+        queue.add(submit_event("WD0213", UcfAction::Submit, None, None));
+        let mut parameters = IndexMap::new();
+        parameters.insert("Id".to_string(), "WD01".to_string());
+        parameters.insert("Data".to_string(), "ClientWidth:1920px".to_string());
+        queue.add(
+            EventBuilder::default()
+                .control("ClientInspector".to_owned())
+                .event("Notify".to_owned())
+                .parameters(parameters)
+                .build()
+                .unwrap(),
+        );
+        assert!(
+            queue
+                .serialize_and_clear_with_form_event()
+                .unwrap()
+                .contains(
+                    "FocusInfo~E004~0040~007B~0022sFocussedId~0022~003A~0022WD0213~0022~007D"
+                )
+        );
+    }
+
+    #[test]
+    fn event_queue_inspect_and_remove() {
+        let mut queue = EventQueue::new();
+        queue.add(change_event("WD01", "a", UcfCardinality::Multiple));
+        queue.add(change_event("WD02", "b", UcfCardinality::Multiple));
+        let peeked = queue.serialize();
+        assert_eq!(queue.len(), 2);
+        let removed = queue.remove_where(|event| event.element_id() == Some("WD01"));
+        assert_eq!(removed.len(), 1);
+        assert_eq!(
+            queue
+                .events()
+                .filter_map(|event| event.element_id())
+                .collect::<Vec<_>>(),
+            vec!["WD02"]
+        );
+        assert!(peeked.contains("WD01"));
+        assert!(!queue.serialize().contains("WD01"));
+    }
+
+    #[test]
+    fn event_queue_transaction() {
+        let mut queue = EventQueue::new();
+        queue.add(change_event("WD01", "a", UcfCardinality::Multiple));
+        queue.begin_transaction();
+        assert_eq!(
+            queue.add(submit_event("WD02", UcfAction::Submit, None, None)),
+            EnqueueEventResult::Enqueued
+        );
+        queue.add(change_event("WD03", "c", UcfCardinality::Multiple));
+        queue.rollback_transaction();
+        assert_eq!(queue.len(), 1);
+
+        queue.begin_transaction();
+        queue.add(submit_event("WD02", UcfAction::Submit, None, None));
+        queue.add(change_event("WD03", "c", UcfCardinality::Multiple));
+        assert!(queue.commit_transaction().should_process());
+        assert_eq!(queue.len(), 3);
     }
 }
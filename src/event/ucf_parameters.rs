@@ -116,10 +116,58 @@ impl UcfParameters {
             Some(UcfAction::Submit) | Some(UcfAction::SubmitAsync)
         )
     }
+
+    /// 이 파라메터를 가진 이벤트가 큐에 한번만 저장될 수 있다면 참을 반환합니다.
+    /// [`UcfCardinality`]값이 `Single`이면 참입니다.
+    pub fn is_single(&self) -> bool {
+        matches!(self.cardinality, Some(UcfCardinality::Single))
+    }
+
+    /// 이 파라메터를 가진 이벤트가 비동기로 전송되어야 한다면 참을 반환합니다.
+    /// [`UcfAction`]값이 `SubmitAsync`이면 참입니다.
+    pub fn is_async(&self) -> bool {
+        matches!(self.action, Some(UcfAction::SubmitAsync))
+    }
+
+    /// 이 파라메터를 가진 이벤트의 전송이 지연되어야 한다면 참을 반환합니다.
+    /// [`UcfDelay`]값이 `Full`이면 참입니다.
+    pub fn is_delayed(&self) -> bool {
+        matches!(self.delay, Some(UcfDelay::Full))
+    }
+
+    /// [`UcfAction`]값을 반환합니다.
+    pub fn action(&self) -> Option<UcfAction> {
+        self.action
+    }
+
+    /// [`UcfCardinality`]값을 반환합니다.
+    pub fn cardinality(&self) -> Option<UcfCardinality> {
+        self.cardinality
+    }
+
+    /// [`UcfTransportMethod`]값을 반환합니다.
+    pub fn transport(&self) -> Option<UcfTransportMethod> {
+        self.transport
+    }
+
+    /// [`UcfResponseData`]값을 반환합니다.
+    pub fn response(&self) -> Option<UcfResponseData> {
+        self.response
+    }
+
+    /// [`UcfDelay`]값을 반환합니다.
+    pub fn delay(&self) -> Option<UcfDelay> {
+        self.delay
+    }
+
+    /// `SyncExecution` 값을 반환합니다.
+    pub fn sync_execution(&self) -> Option<bool> {
+        self.sync_execution
+    }
 }
 
 /// 이벤트가 큐에 저장될지 바로 전송될지 여부
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum UcfAction {
     /// 이벤트가 바로 전송되어야 함
@@ -146,7 +194,7 @@ impl Display for UcfAction {
 }
 
 /// 동일한 종류의 이벤트가 큐에 동시에 들어갈 수 있는지 여부
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum UcfCardinality {
     /// 동일한 이벤트가 큐에 여러번 들어갈 수 있음
@@ -170,7 +218,7 @@ impl Display for UcfCardinality {
 }
 
 /// 이벤트의 응답 방법을 표현할 수 있는지 여부
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum UcfResponseData {
     /// 이벤트로 인해 변경된 부분을 포함한 전체 컨텐츠를 응답
@@ -193,8 +241,9 @@ impl Display for UcfResponseData {
     }
 }
 
+/// 이벤트 큐 전송 시 전체 폼 데이터를 전송할 지 여부
 #[allow(missing_docs)]
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum UcfTransportMethod {
     Full,
@@ -214,7 +263,7 @@ impl Display for UcfTransportMethod {
 
 /// 이벤트의 반영 딜레이 여부
 #[allow(missing_docs)]
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum UcfDelay {
     Full,
@@ -25,7 +25,6 @@
 //! 추가 정보는 [`LoadingPlaceholder`], [`ClientInspector`] 와 [`Custom`] 엘리먼트를 참고하십시오.
 //! ```ignore
 //! use futures::executor::block_on;
-//! use wdpe::event::event_queue::EnqueueEventResult;
 //! use wdpe::requests::WebDynproRequests as _;
 //! use wdpe::requests::{EventProcessResult, WebDynproState};
 //! use wdpe::{define_elements, element::{text::Caption, system::{ClientInspector, Custom, CustomClientInfo, LoadingPlaceholder}}};
@@ -82,7 +81,7 @@
 //!     ) -> Result<EventProcessResult, WebDynproError> {
 //!         let enqueue_result = self.state.add_event(event).await;
 //!
-//!         if (matches!(enqueue_result, EnqueueEventResult::ShouldProcess)) || force_send {
+//!         if enqueue_result.should_process() || force_send {
 //!             let serialized_events = self.state.serialize_and_clear_with_form_event().await?;
 //!             let update = {
 //!                 self.client
@@ -171,6 +170,8 @@ pub mod requests;
 #[cfg(feature = "element")]
 pub use scraper;
 
+pub use indexmap;
+
 // Re-export proc-macros from wdpe-macros
 #[cfg(feature = "element")]
-pub use wdpe_macros::{WdElement, WdLsData, WdSubElement, wd_event};
+pub use wdpe_macros::{FromSapTable, WdElement, WdLsData, WdSubElement, wd_event};
@@ -2,11 +2,24 @@ use crate::body::{Body, BodyUpdate, BodyUpdateResult};
 use crate::error::{ClientError, WebDynproError};
 use crate::event::{
     Event,
-    event_queue::{EnqueueEventResult, EventQueue},
+    event_queue::{EnqueueEventResult, EventQueue, FormRequest},
 };
-use tokio::sync::Mutex;
+use crate::requests::WebDynproRequests;
+use tokio::sync::{Mutex, MutexGuard};
 use url::Url;
 
+#[cfg(feature = "element")]
+mod sap_table;
+
+#[cfg(feature = "element")]
+mod suggest;
+
+#[cfg(feature = "element")]
+mod popup;
+
+#[cfg(feature = "element")]
+pub use self::sap_table::SapTablePager;
+
 /// WebDynpro 애플리케이션의 상태를 관리하는 구조체
 #[derive(Debug)]
 pub struct WebDynproState {
@@ -59,11 +72,64 @@ impl WebDynproState {
         Ok(self.body.apply(update)?)
     }
 
+    /// 이벤트를 이벤트 큐에 추가하고, 큐가 처리되어야 하거나 `force_send`가 참이라면 큐를 서버에 전송하여 페이지 문서를 갱신합니다.
+    pub async fn process_event(
+        &mut self,
+        client: &impl WebDynproRequests,
+        force_send: bool,
+        event: Event,
+    ) -> Result<EventProcessResult, WebDynproError> {
+        let queue = self.event_queue.get_mut();
+        let enqueue_result = queue.add(event);
+        if !(enqueue_result.should_process() || force_send) {
+            return Ok(EventProcessResult::Enqueued);
+        }
+        let serialized_events = queue.serialize_and_clear_with_form_event()?;
+        let update = client
+            .send_events(&self.base_url, self.body.ssr_client(), &serialized_events)
+            .await?;
+        let result = self.mutate_body(update)?;
+        Ok(EventProcessResult::Sent(result))
+    }
+
     /// 이벤트를 이벤트 큐에 추가합니다.
     pub async fn add_event(&self, event: Event) -> EnqueueEventResult {
         self.event_queue.lock().await.add(event)
     }
 
+    /// 여러 이벤트를 하나의 트랜잭션으로 이벤트 큐에 추가합니다.
+    /// 모든 이벤트가 추가된 후에 큐가 처리되어야 할 지를 반환합니다.
+    /// 이미 트랜잭션이 진행 중이라면 해당 트랜잭션에 이벤트를 추가하기만 하고 커밋하지 않으며, [`EnqueueEventResult::Enqueued`]를 반환합니다.
+    pub async fn add_events(&self, events: impl IntoIterator<Item = Event>) -> EnqueueEventResult {
+        let mut queue = self.event_queue.lock().await;
+        let owns_transaction = !queue.is_in_transaction();
+        queue.begin_transaction();
+        for event in events {
+            queue.add(event);
+        }
+        if owns_transaction {
+            queue.commit_transaction()
+        } else {
+            EnqueueEventResult::Enqueued
+        }
+    }
+
+    /// 이벤트 큐에 대한 잠금을 획득하고 큐를 반환합니다.
+    /// 큐의 내용을 확인하거나, 이벤트를 제거하거나, 트랜잭션을 직접 관리할 때 사용합니다.
+    pub async fn event_queue(&self) -> MutexGuard<'_, EventQueue> {
+        self.event_queue.lock().await
+    }
+
+    /// 이벤트 큐에 저장된 이벤트들을 반환합니다.
+    pub async fn pending_events(&self) -> Vec<Event> {
+        self.event_queue.lock().await.events().cloned().collect()
+    }
+
+    /// 이벤트 큐의 내용을 큐를 비우지 않고 직렬화합니다.
+    pub async fn peek_serialized(&self) -> String {
+        self.event_queue.lock().await.serialize()
+    }
+
     /// 이벤트 큐의 내용을 직렬화하고 큐를 비웁니다.
     pub async fn serialize_and_clear(&self) -> String {
         self.event_queue.lock().await.serialize_and_clear()
@@ -76,6 +142,17 @@ impl WebDynproState {
             .await
             .serialize_and_clear_with_form_event()
     }
+
+    /// 이벤트 큐의 내용을 주어진 [`FormRequest`]로 생성한 Form 이벤트와 함께 직렬화하고 큐를 비웁니다.
+    pub async fn serialize_and_clear_with_form_request(
+        &self,
+        form_request: &FormRequest,
+    ) -> Result<String, ClientError> {
+        self.event_queue
+            .lock()
+            .await
+            .serialize_and_clear_with_form_request(form_request)
+    }
 }
 
 /// SSR 클라이언트 정보를 담는 구조체
@@ -114,3 +191,63 @@ pub enum EventProcessResult {
     /// 전달받은 이벤트가 큐에 추가된 후 서버에 전송되었을 경우
     Sent(BodyUpdateResult),
 }
+
+#[cfg(test)]
+mod test {
+    use indexmap::IndexMap;
+    use url::Url;
+
+    use super::WebDynproState;
+    use crate::body::test::body;
+    use crate::event::{
+        Event, EventBuilder,
+        event_queue::EnqueueEventResult,
+        ucf_parameters::{UcfAction, UcfParametersBuilder},
+    };
+
+    fn state() -> WebDynproState {
+        WebDynproState::new(
+            Url::parse("https://example.com/sap/bc/webdynpro/SAP/").unwrap(),
+            "TEST".to_string(),
+            body(""),
+        )
+    }
+
+    fn event(id: &str, action: UcfAction) -> Event {
+        let mut parameters = IndexMap::new();
+        parameters.insert("Id".to_string(), id.to_string());
+        EventBuilder::default()
+            .control("Button".to_owned())
+            .event("Press".to_owned())
+            .parameters(parameters)
+            .ucf_parameters(
+                UcfParametersBuilder::default()
+                    .action(Some(action))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn add_events_in_open_transaction() {
+        let state = state();
+        state.event_queue().await.begin_transaction();
+        let result = state
+            .add_events([
+                event("WD01", UcfAction::Submit),
+                event("WD02", UcfAction::Enqueue),
+            ])
+            .await;
+        assert!(matches!(result, EnqueueEventResult::Enqueued));
+        assert!(state.event_queue().await.is_in_transaction());
+
+        state.event_queue().await.rollback_transaction();
+        assert!(state.pending_events().await.is_empty());
+
+        let result = state.add_events([event("WD01", UcfAction::Submit)]).await;
+        assert!(result.should_process());
+        assert!(!state.event_queue().await.is_in_transaction());
+    }
+}
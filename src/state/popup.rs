@@ -0,0 +1,34 @@
+use super::{EventProcessResult, WebDynproState};
+use crate::command::WebDynproCommandExecutor as _;
+use crate::command::element::layout::{
+    ActivePopupWindowCommand, PopupWindowPressButtonEventCommand,
+};
+use crate::element::layout::PopupWindowDef;
+use crate::element::parser::ElementParser;
+use crate::error::{BodyError, WebDynproError};
+use crate::requests::WebDynproRequests;
+
+impl WebDynproState {
+    /// 가장 위에 표시된 [`PopupWindow`](crate::element::layout::PopupWindow)의 정의를 반환합니다.
+    pub fn active_popup(&self) -> Result<Option<PopupWindowDef>, WebDynproError> {
+        ElementParser::new(&self.body).read(ActivePopupWindowCommand::new())
+    }
+
+    /// 가장 위에 표시된 [`PopupWindow`](crate::element::layout::PopupWindow)의 버튼 중 주어진 텍스트를 가진 버튼을 눌러 응답합니다.
+    ///
+    /// 표시된 창이 없다면 아무 요청도 보내지 않고 [`BodyError::NoSuchElement`] 오류를 반환합니다.
+    pub async fn answer_popup(
+        &mut self,
+        client: &impl WebDynproRequests,
+        button_text: &str,
+    ) -> Result<EventProcessResult, WebDynproError> {
+        let event = {
+            let parser = ElementParser::new(&self.body);
+            let popup = parser
+                .read(ActivePopupWindowCommand::new())?
+                .ok_or_else(|| BodyError::NoSuchElement("PopupWindow".to_owned()))?;
+            parser.read(PopupWindowPressButtonEventCommand::new(popup, button_text))?
+        };
+        self.process_event(client, true, event).await
+    }
+}
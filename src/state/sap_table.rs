@@ -0,0 +1,398 @@
+use std::collections::HashSet;
+
+use super::WebDynproState;
+use crate::element::Element as _;
+use crate::element::complex::{
+    SapTableDef,
+    sap_table::{
+        FromSapTable, SapTableBody, SapTableColumnStitcher, SapTableHierarchyRow, SapTableRow,
+    },
+};
+use crate::element::definition::ElementDefinition as _;
+use crate::element::parser::ElementParser;
+use crate::error::WebDynproError;
+use crate::requests::WebDynproRequests;
+
+impl WebDynproState {
+    /// 계층형 [`SapTable`](crate::element::complex::SapTable)의 접힌 행을 모두 펼친 후 테이블 내용을 반환합니다.
+    ///
+    /// 펼치기 이벤트마다 서버에 요청을 전송하며, 한번 펼치기를 시도한 행은 다시 시도하지 않습니다.
+    /// 행은 펼칠 때마다 인덱스가 바뀌므로 유저 데이터(없다면 계층 셀의 Id)로 구분합니다.
+    /// 현재 렌더링된 행만 대상으로 하며, 스크롤하지 않으면 보이지 않는 행은 펼치지 않습니다.
+    pub async fn expand_all_sap_table(
+        &mut self,
+        client: &impl WebDynproRequests,
+        table_def: &SapTableDef,
+    ) -> Result<SapTableBody, WebDynproError> {
+        let mut attempted = HashSet::new();
+        loop {
+            let event = {
+                let parser = ElementParser::new(&self.body);
+                let table = parser.element_from_def(table_def)?;
+                let body = table.table()?;
+                let Some((key, row_index, cell_id)) =
+                    next_collapsed_row(&body.hierarchy(&parser)?, &attempted)
+                else {
+                    return Ok(body.clone());
+                };
+                attempted.insert(key);
+                table.toggle_hierarchical(row_index, &cell_id, true)?
+            };
+            self.process_event(client, true, event).await?;
+        }
+    }
+
+    /// [`SapTable`](crate::element::complex::SapTable)을 좌우로 스크롤하며 현재 보이는 행들의 모든 열을 이어붙여 반환합니다.
+    ///
+    /// 고정 열을 찾기 위해 처음에는 한 열만 스크롤하고, 이후에는 지금까지 읽은 스크롤 가능한 열의 수만큼 스크롤합니다.
+    pub async fn read_full_width_sap_table(
+        &mut self,
+        client: &impl WebDynproRequests,
+        table_def: &SapTableDef,
+    ) -> Result<SapTableColumnStitcher, WebDynproError> {
+        let mut stitcher = SapTableColumnStitcher::new();
+        let mut first_visible = 0u32;
+        loop {
+            let event = {
+                let parser = ElementParser::new(&self.body);
+                let table = parser.element_from_def(table_def)?;
+                let new_columns = stitcher.add_window(table.table()?, &parser)?;
+                if new_columns == 0 {
+                    break;
+                }
+                let col_count = table.lsdata().col_count().copied();
+                if col_count.is_some_and(|count| stitcher.column_count() >= count as usize) {
+                    break;
+                }
+                let next = if stitcher.window_count() == 1 {
+                    1
+                } else {
+                    stitcher.scrollable_column_count() as u32
+                };
+                if next <= first_visible {
+                    break;
+                }
+                first_visible = next;
+                table.horizontal_scroll(next)?
+            };
+            self.process_event(client, true, event).await?;
+        }
+        Ok(stitcher)
+    }
+
+    /// [`SapTable`](crate::element::complex::SapTable)을 끝까지 스크롤하며 모든 행을 읽어 반환합니다.
+    pub async fn read_all_sap_table_rows(
+        &mut self,
+        client: &impl WebDynproRequests,
+        table_def: &SapTableDef,
+    ) -> Result<Vec<SapTableRow>, WebDynproError> {
+        let mut pager = SapTablePager::new(table_def.clone());
+        let mut rows = Vec::new();
+        while let Some(page) = pager.next_rows(self, client).await? {
+            rows.extend(page);
+        }
+        Ok(rows)
+    }
+
+    /// [`SapTable`](crate::element::complex::SapTable)을 끝까지 스크롤하며 모든 행을 [`FromSapTable`]을 구현하는 형으로 변환하여 반환합니다.
+    pub async fn read_all_sap_table<T>(
+        &mut self,
+        client: &impl WebDynproRequests,
+        table_def: &SapTableDef,
+    ) -> Result<Vec<T>, WebDynproError>
+    where
+        T: for<'body> FromSapTable<'body>,
+    {
+        let mut pager = SapTablePager::new(table_def.clone());
+        let mut rows = Vec::new();
+        while let Some(page) = pager.next_page::<T>(self, client).await? {
+            rows.extend(page);
+        }
+        Ok(rows)
+    }
+}
+
+/// [`SapTable`](crate::element::complex::SapTable)을 `VerticalScroll` 이벤트로 스크롤하며 아직 읽지 않은 행을 페이지 단위로 반환하는 구조체
+///
+/// 첫 페이지는 맨 위(`FirstVisibleItemIndex` 0)로 스크롤하여 읽고, 이후에는 직전 페이지에 렌더링된 행의 수만큼 스크롤합니다.
+/// 행 인덱스(`rr`)와 `FirstVisibleItemIndex`의 기준이 같다고 가정하지 않으며, 이미 읽은 행은 `rr`을 기준으로 제외됩니다.
+/// `rr`이 없는 행은 구분할 수 없으므로 반환하지 않습니다.
+///
+/// 테이블의 전체 행 수(`row_count`)를 알 수 있다면 그만큼 읽었을 때 멈추고, 그렇지 않다면 새로운 행이 없는 페이지가 나올 때까지 스크롤합니다.
+/// 서버가 계속 새로운 행을 보내더라도 최대 [`SapTablePager::MAX_PAGES`]개의 페이지만 읽습니다.
+#[derive(Debug)]
+pub struct SapTablePager {
+    table_def: SapTableDef,
+    seen: HashSet<u32>,
+    next_index: u32,
+    page_count: u32,
+    finished: bool,
+}
+
+impl SapTablePager {
+    /// 한 페이저가 읽을 수 있는 최대 페이지 수
+    pub const MAX_PAGES: u32 = 10_000;
+
+    /// 새로운 페이저를 생성합니다.
+    pub fn new(table_def: SapTableDef) -> Self {
+        Self {
+            table_def,
+            seen: HashSet::new(),
+            next_index: 0,
+            page_count: 0,
+            finished: false,
+        }
+    }
+
+    /// 지금까지 읽은 행의 개수를 반환합니다.
+    pub fn read_count(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// 모든 행을 읽었다면 참을 반환합니다.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// 다음 페이지의 새로운 행들을 반환합니다. 더 이상 읽을 행이 없다면 `None`을 반환합니다.
+    pub async fn next_rows(
+        &mut self,
+        state: &mut WebDynproState,
+        client: &impl WebDynproRequests,
+    ) -> Result<Option<Vec<SapTableRow>>, WebDynproError> {
+        self.next_with(state, client, |_parser, body, rows| {
+            Ok(rows.into_iter().map(|index| body[index].clone()).collect())
+        })
+        .await
+    }
+
+    /// 다음 페이지의 새로운 행들을 [`FromSapTable`]을 구현하는 형으로 변환하여 반환합니다. 더 이상 읽을 행이 없다면 `None`을 반환합니다.
+    pub async fn next_page<T>(
+        &mut self,
+        state: &mut WebDynproState,
+        client: &impl WebDynproRequests,
+    ) -> Result<Option<Vec<T>>, WebDynproError>
+    where
+        T: for<'body> FromSapTable<'body>,
+    {
+        self.next_with(state, client, |parser, body, rows| {
+            rows.into_iter()
+                .map(|index| body[index].try_row_into::<T>(body.header(), parser))
+                .collect()
+        })
+        .await
+    }
+
+    async fn next_with<R>(
+        &mut self,
+        state: &mut WebDynproState,
+        client: &impl WebDynproRequests,
+        convert: impl Fn(&ElementParser, &SapTableBody, Vec<usize>) -> Result<Vec<R>, WebDynproError>,
+    ) -> Result<Option<Vec<R>>, WebDynproError> {
+        if self.finished {
+            return Ok(None);
+        }
+        let event = {
+            let parser = ElementParser::new(state.body());
+            parser.element_from_def(&self.table_def)?.vertical_scroll(
+                self.next_index,
+                "",
+                "SCROLLBAR",
+                false,
+                false,
+                false,
+                false,
+            )?
+        };
+        state.process_event(client, true, event).await?;
+        let parser = ElementParser::new(state.body());
+        let table = parser.element_from_def(&self.table_def)?;
+        let row_count = table.lsdata().row_count().copied();
+        let body = table.table()?;
+        let new_rows = self.read_page(body, row_count);
+        if new_rows.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(convert(&parser, body, new_rows)?))
+    }
+
+    // 현재 페이지에서 처음 읽은 행들의 위치를 반환하고 다음에 스크롤할 위치를 갱신합니다.
+    fn read_page(&mut self, body: &SapTableBody, row_count: Option<u32>) -> Vec<usize> {
+        let new_rows = body
+            .iter()
+            .enumerate()
+            .filter_map(|(index, row)| {
+                let row_index = row.row_index()?;
+                self.seen.insert(row_index).then_some(index)
+            })
+            .collect::<Vec<usize>>();
+        if new_rows.is_empty() {
+            self.finished = true;
+            return new_rows;
+        }
+        let row_indices = body
+            .iter()
+            .filter_map(|row| row.row_index())
+            .collect::<Vec<u32>>();
+        if let (Some(first), Some(last)) = (row_indices.iter().min(), row_indices.iter().max()) {
+            self.next_index += last - first + 1;
+        }
+        self.page_count += 1;
+        if row_count.is_some_and(|count| self.seen.len() >= count as usize) {
+            self.finished = true;
+        } else if self.page_count >= Self::MAX_PAGES {
+            tracing::warn!(
+                table = self.table_def.id(),
+                pages = self.page_count,
+                "stopped reading sap table at page limit"
+            );
+            self.finished = true;
+        }
+        new_rows
+    }
+}
+
+// 아직 펼치기를 시도하지 않은 첫번째 접힌 행의 구분 키, 인덱스, 계층 셀 Id를 반환합니다.
+fn next_collapsed_row(
+    rows: &[SapTableHierarchyRow],
+    attempted: &HashSet<String>,
+) -> Option<(String, u32, String)> {
+    rows.iter().find_map(|row| {
+        if !row.is_collapsed() {
+            return None;
+        }
+        let cell_id = row.cell_id()?;
+        let key = row.row().user_data().unwrap_or(cell_id);
+        if attempted.contains(key) {
+            return None;
+        }
+        Some((key.to_owned(), row.row().row_index()?, cell_id.to_owned()))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::{SapTablePager, next_collapsed_row};
+    use crate::element::complex::SapTableDef;
+    use crate::element::complex::sap_table::test::with_table;
+    use crate::element::definition::ElementDefinition as _;
+
+    fn tree_row(index: u32, user_data: &str, level: i32, status: &str) -> String {
+        format!(
+            r#"<tr rt="1" rr="{index}" uDat="{user_data}"><td subct="HIC" id="ST-{index}-0" lsdata="{{4:{level},5:'{status}'}}"></td></tr>"#
+        )
+    }
+
+    fn next(rows: &[String], attempted: &[&str]) -> Option<(String, u32, String)> {
+        let attempted = attempted
+            .iter()
+            .map(|key| key.to_string())
+            .collect::<HashSet<_>>();
+        with_table(rows, |parser, body| {
+            next_collapsed_row(&body.hierarchy(parser).unwrap(), &attempted)
+        })
+    }
+
+    fn page(range: std::ops::RangeInclusive<u32>) -> Vec<String> {
+        range
+            .map(|index| {
+                format!(r#"<tr rt="1" rr="{index}"><td subct="STC" id="ST-{index}-0"></td></tr>"#)
+            })
+            .collect()
+    }
+
+    fn read_page(pager: &mut SapTablePager, rows: &[String], row_count: Option<u32>) -> Vec<u32> {
+        with_table(rows, |_, body| {
+            pager
+                .read_page(body, row_count)
+                .into_iter()
+                .filter_map(|index| body[index].row_index())
+                .collect()
+        })
+    }
+
+    #[test]
+    fn pager_advances_by_rendered_rows() {
+        let mut pager = SapTablePager::new(SapTableDef::new_dynamic("ST".to_string()));
+        assert_eq!(pager.next_index, 0);
+        // 행 인덱스는 1부터 시작하지만, 다음 위치는 렌더링된 행의 수만큼만 증가합니다.
+        assert_eq!(read_page(&mut pager, &page(1..=3), Some(7)), vec![1, 2, 3]);
+        assert_eq!(pager.next_index, 3);
+        assert_eq!(read_page(&mut pager, &page(4..=6), Some(7)), vec![4, 5, 6]);
+        assert_eq!(pager.next_index, 6);
+        assert!(!pager.is_finished());
+        // 마지막 페이지는 서버가 위치를 당겨 이미 읽은 행이 다시 렌더링됩니다.
+        assert_eq!(read_page(&mut pager, &page(5..=7), Some(7)), vec![7]);
+        assert_eq!(pager.read_count(), 7);
+        assert!(pager.is_finished());
+    }
+
+    #[test]
+    fn pager_finishes_without_new_rows() {
+        let mut pager = SapTablePager::new(SapTableDef::new_dynamic("ST".to_string()));
+        assert_eq!(read_page(&mut pager, &page(1..=3), Some(10)), vec![1, 2, 3]);
+        assert!(read_page(&mut pager, &page(1..=3), Some(10)).is_empty());
+        assert!(pager.is_finished());
+        assert_eq!(pager.read_count(), 3);
+    }
+
+    #[test]
+    fn pager_without_row_count_reads_until_no_new_rows() {
+        let mut pager = SapTablePager::new(SapTableDef::new_dynamic("ST".to_string()));
+        assert_eq!(read_page(&mut pager, &page(1..=3), None), vec![1, 2, 3]);
+        assert!(!pager.is_finished());
+        // rr이 없는 행은 구분할 수 없으므로 건너뜁니다.
+        let mut rows = page(4..=5);
+        rows.push(r#"<tr rt="1"><td subct="STC" id="ST-X-0"></td></tr>"#.to_string());
+        assert_eq!(read_page(&mut pager, &rows, None), vec![4, 5]);
+        assert!(!pager.is_finished());
+        assert!(read_page(&mut pager, &page(3..=5), None).is_empty());
+        assert!(pager.is_finished());
+        assert_eq!(pager.read_count(), 5);
+    }
+
+    #[test]
+    fn pager_stops_at_page_limit() {
+        let mut pager = SapTablePager::new(SapTableDef::new_dynamic("ST".to_string()));
+        pager.page_count = SapTablePager::MAX_PAGES - 1;
+        assert_eq!(read_page(&mut pager, &page(1..=3), None), vec![1, 2, 3]);
+        assert!(pager.is_finished());
+    }
+
+    #[test]
+    fn next_collapsed_sibling_below_expanded_node() {
+        let before = [
+            tree_row(1, "A", 0, "COLLAPSED"),
+            tree_row(2, "B", 0, "COLLAPSED"),
+        ];
+        assert_eq!(
+            next(&before, &[]),
+            Some(("A".to_string(), 1, "ST-1-0".to_string()))
+        );
+
+        // A를 펼치면 자식 행이 끼어들어 B의 인덱스가 밀립니다.
+        let after = [
+            tree_row(1, "A", 0, "EXPANDED"),
+            tree_row(2, "A1", 1, "LEAF"),
+            tree_row(3, "B", 0, "COLLAPSED"),
+        ];
+        assert_eq!(
+            next(&after, &["A"]),
+            Some(("B".to_string(), 3, "ST-3-0".to_string()))
+        );
+
+        // 예전 인덱스(2)에 다른 접힌 행이 오더라도 키가 다르므로 건너뛰지 않습니다.
+        let nested = [
+            tree_row(1, "A", 0, "EXPANDED"),
+            tree_row(2, "A1", 1, "COLLAPSED"),
+            tree_row(3, "B", 0, "COLLAPSED"),
+        ];
+        assert_eq!(
+            next(&nested, &["A", "B"]),
+            Some(("A1".to_string(), 2, "ST-2-0".to_string()))
+        );
+        assert_eq!(next(&nested, &["A", "A1", "B"]), None);
+    }
+}
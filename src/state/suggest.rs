@@ -0,0 +1,50 @@
+use super::WebDynproState;
+use crate::element::parser::ElementParser;
+use crate::element::selection::{ComboBoxDef, list_box::item::ListBoxItemInfo};
+use crate::element::text::InputFieldDef;
+use crate::error::WebDynproError;
+use crate::requests::WebDynproRequests;
+
+impl WebDynproState {
+    /// [`ComboBox`](crate::element::selection::ComboBox)에 입력 중인 값에 대한 추천 목록을 서버에 요청하고, 서버가 전달한 추천 선택지를 반환합니다.
+    ///
+    /// 서버에 추천 목록을 요청하지 않는 [`ComboBox`](crate::element::selection::ComboBox)라면 요청을 보내지 않고 현재 선택지 중 일치하는 선택지를 반환합니다.
+    pub async fn suggest_combo_box(
+        &mut self,
+        client: &impl WebDynproRequests,
+        combo_box_def: &ComboBoxDef,
+        value: &str,
+    ) -> Result<Vec<ListBoxItemInfo>, WebDynproError> {
+        let event = {
+            let parser = ElementParser::new(&self.body);
+            let combo_box = parser.element_from_def(combo_box_def)?;
+            if !combo_box.is_server_suggest() {
+                return combo_box.suggestions(value, &parser);
+            }
+            combo_box.suggest(value)?
+        };
+        self.process_event(client, true, event).await?;
+        let parser = ElementParser::new(&self.body);
+        parser
+            .element_from_def(combo_box_def)?
+            .suggestions(value, &parser)
+    }
+
+    /// [`InputField`](crate::element::text::InputField)에 입력 중인 값에 대한 추천 목록을 서버에 요청하고, 서버가 전달한 추천 아이템을 반환합니다.
+    pub async fn suggest_input_field(
+        &mut self,
+        client: &impl WebDynproRequests,
+        input_field_def: &InputFieldDef,
+        value: &str,
+    ) -> Result<Vec<ListBoxItemInfo>, WebDynproError> {
+        let event = {
+            let parser = ElementParser::new(&self.body);
+            parser.element_from_def(input_field_def)?.suggest(value)?
+        };
+        self.process_event(client, true, event).await?;
+        let parser = ElementParser::new(&self.body);
+        parser
+            .element_from_def(input_field_def)?
+            .suggestions(value, &parser)
+    }
+}
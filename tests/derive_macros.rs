@@ -10,8 +10,10 @@ fn derive_macro_tests() {
     let t = trybuild::TestCases::new();
     // Happy-path cases (WdLsData works externally since it only uses serde/std paths)
     t.pass("tests/ui/pass_lsdata.rs");
+    t.pass("tests/ui/pass_from_sap_table.rs");
     // Error cases (rejected before crate-internal path generation)
     t.compile_fail("tests/ui/fail_missing_element_ref.rs");
     t.compile_fail("tests/ui/fail_duplicate_element_ref.rs");
     t.compile_fail("tests/ui/fail_lsevents_without_interactable.rs");
+    t.compile_fail("tests/ui/fail_from_sap_table_duplicate_source.rs");
 }
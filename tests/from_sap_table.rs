@@ -0,0 +1,96 @@
+//! Runtime tests for rows converted through `#[derive(FromSapTable)]`.
+#![cfg(feature = "element")]
+
+use wdpe::FromSapTable;
+use wdpe::body::Body;
+use wdpe::element::complex::SapTableDef;
+use wdpe::element::definition::ElementDefinition as _;
+use wdpe::element::parser::ElementParser;
+use wdpe::error::{ElementError, WebDynproError};
+
+#[derive(FromSapTable, Debug, PartialEq)]
+struct Lecture {
+    #[wd_table(title = "Name")]
+    name: String,
+    #[wd_table(index = 1)]
+    credit: f32,
+    #[wd_table(title = "Note")]
+    note: Option<String>,
+}
+
+fn header_cell(id: &str, title: &str) -> String {
+    format!(
+        r#"<th subct="HC" id="{id}"><div id="{id}-CONTENT"><span ct="CP" id="{id}-cp" lsdata="{{1:'{title}'}}"></span></div></th>"#
+    )
+}
+
+fn row(index: u32, texts: &[&str]) -> String {
+    let cells = texts
+        .iter()
+        .enumerate()
+        .map(|(col, text)| {
+            format!(
+                r#"<td subct="STC" id="C{index}-{col}"><span ct="TV" id="C{index}-{col}-tv" lsdata="{{}}">{text}</span></td>"#
+            )
+        })
+        .collect::<String>();
+    format!(r#"<tr rt="1" rr="{index}">{cells}</tr>"#)
+}
+
+fn body(rows: &[String]) -> Body {
+    let header = [
+        header_cell("H0", "Name"),
+        header_cell("H1", "Credit"),
+        header_cell("H2", "Note"),
+    ]
+    .concat();
+    Body::new(format!(
+        r#"<html><body><div ct="ST" id="ST" lsdata="{{}}"><table><tbody id="ST-contentTBody"><tr rt="2">{header}</tr>{}</tbody></table></div><form id="sap.client.SsrClient.form" action="/sap/bc/webdynpro/SAP/TEST"><input id="sap-charset" value="utf-8"><input id="sap-wd-secure-id" value="SECURE"><input id="fesrAppName" value="TEST"><input id="fesrUseBeacon" value="false"></form></body></html>"#,
+        rows.concat()
+    ))
+    .unwrap()
+}
+
+fn lectures(body: &Body) -> Result<Vec<Lecture>, WebDynproError> {
+    let parser = ElementParser::new(body);
+    let table = parser.element_from_def(&SapTableDef::new_dynamic("ST".to_string()))?;
+    table.table()?.try_table_into::<Lecture>(&parser)
+}
+
+#[test]
+fn derive_parses_rows() {
+    let body = body(&[
+        row(1, &["Algebra", "3", ""]),
+        row(2, &["Physics", "2.5", "Lab"]),
+    ]);
+    assert_eq!(
+        lectures(&body).unwrap(),
+        vec![
+            Lecture {
+                name: "Algebra".to_string(),
+                credit: 3.0,
+                note: None,
+            },
+            Lecture {
+                name: "Physics".to_string(),
+                credit: 2.5,
+                note: Some("Lab".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn derive_reports_invalid_cell() {
+    let body = body(&[
+        row(1, &["Algebra", "3", ""]),
+        row(2, &["Physics", "two", ""]),
+    ]);
+    let Err(WebDynproError::Element(err)) = lectures(&body) else {
+        panic!("expected an element error");
+    };
+    let ElementError::InvalidTableCell { row, col, text, .. } = *err else {
+        panic!("expected InvalidTableCell, got {err:?}");
+    };
+    assert_eq!((row, col, text.as_str()), (Some(2), 1, "two"));
+}
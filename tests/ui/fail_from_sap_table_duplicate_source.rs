@@ -0,0 +1,9 @@
+use wdpe::FromSapTable;
+
+#[derive(FromSapTable)]
+pub struct Lecture {
+    #[wd_table(title = "과목명", index = 0)]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use wdpe::FromSapTable;
+
+#[derive(FromSapTable)]
+#[allow(unused)]
+pub struct Lecture {
+    #[wd_table(title = "과목명")]
+    name: String,
+    #[wd_table(index = 3)]
+    credit: f32,
+    #[wd_table(title = "비고")]
+    note: Option<String>,
+    professor: Option<String>,
+}
+
+fn main() {
+    let _ = <Lecture as wdpe::element::complex::sap_table::FromSapTable>::from_table;
+}
@@ -91,7 +91,7 @@ fn wd_event_inner(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
         })
         .collect();
 
-    // Build HashMap insertions for event parameters
+    // Build IndexMap insertions for event parameters (insertion order is the serialization order)
     let param_inserts: Vec<_> = args
         .params
         .iter()
@@ -111,8 +111,8 @@ fn wd_event_inner(attr: TokenStream, item: TokenStream) -> Result<TokenStream> {
             #(#fn_params,)*
         ) -> Result<crate::event::Event, crate::error::WebDynproError> {
             use crate::element::Interactable as _;
-            let mut parameters: std::collections::HashMap<String, String> =
-                std::collections::HashMap::new();
+            let mut parameters: indexmap::IndexMap<String, String> =
+                indexmap::IndexMap::new();
             parameters.insert("Id".to_string(), self.id.clone().to_string());
             #(#param_inserts)*
             self.fire_event(#event_name.to_string(), parameters)
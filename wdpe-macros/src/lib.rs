@@ -4,6 +4,7 @@ mod element;
 mod event;
 mod lsdata;
 mod subelement;
+mod table;
 pub(crate) mod utils;
 
 use proc_macro::TokenStream;
@@ -115,3 +116,31 @@ pub fn derive_wd_subelement(input: TokenStream) -> TokenStream {
 pub fn wd_event(attr: TokenStream, item: TokenStream) -> TokenStream {
     event::wd_event_impl(attr.into(), item.into()).into()
 }
+
+/// Derive macro that implements `FromSapTable` for a struct by mapping
+/// table columns to fields.
+///
+/// Each field is mapped to a column by `#[wd_table(title = "...")]` (header
+/// caption) or `#[wd_table(index = N)]` (zero-based position). Fields without
+/// the attribute use the field name as the header title. Cell texts are
+/// converted with `FromStr`, and conversion failures report the row index,
+/// column index and raw text. `Option<T>` fields become `None` when the column
+/// is missing or the cell is empty.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(FromSapTable)]
+/// pub struct Lecture {
+///     #[wd_table(title = "과목명")]
+///     name: String,
+///     #[wd_table(index = 3)]
+///     credit: f32,
+///     #[wd_table(title = "비고")]
+///     note: Option<String>,
+/// }
+/// ```
+#[proc_macro_derive(FromSapTable, attributes(wd_table))]
+pub fn derive_from_sap_table(input: TokenStream) -> TokenStream {
+    table::derive_from_sap_table_impl(input.into()).into()
+}
@@ -0,0 +1,190 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Error, Fields, LitInt, LitStr, Result, parse2};
+
+/// How a struct field is mapped to a column of the table.
+enum ColumnSource {
+    /// Column whose header caption equals the given title.
+    Title(String),
+    /// Column at the given zero-based position.
+    Index(usize),
+}
+
+/// Implementation for the `#[derive(FromSapTable)]` derive macro.
+///
+/// Each named field is mapped to a column by `#[wd_table(title = "...")]` or
+/// `#[wd_table(index = N)]`. Fields without the attribute use the field name
+/// as the header title. The cell text is converted with `FromStr`; fields of
+/// type `Option<T>` become `None` when the column is missing or the cell is empty.
+pub fn derive_from_sap_table_impl(input: TokenStream) -> TokenStream {
+    match derive_from_sap_table_inner(input) {
+        Ok(tokens) => tokens,
+        Err(e) => e.to_compile_error(),
+    }
+}
+
+fn derive_from_sap_table_inner(input: TokenStream) -> Result<TokenStream> {
+    let input: DeriveInput = parse2(input)?;
+    let name = &input.ident;
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "FromSapTable cannot be derived for generic structs",
+        ));
+    }
+
+    let fields = match &input.data {
+        syn::Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "FromSapTable can only be applied to structs with named fields",
+                ));
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "FromSapTable can only be applied to structs",
+            ));
+        }
+    };
+
+    let mut field_bindings = Vec::new();
+    let mut field_names = Vec::new();
+
+    for field in fields {
+        let field_name = field
+            .ident
+            .as_ref()
+            .ok_or_else(|| Error::new_spanned(field, "FromSapTable fields must be named"))?;
+        let source = extract_wd_table_source(&field.attrs)?
+            .unwrap_or_else(|| ColumnSource::Title(field_name.to_string()));
+
+        let (column, column_desc) = match &source {
+            ColumnSource::Title(title) => (
+                quote! { header.and_then(|header| header.column_index(#title, parser)) },
+                format!("Column with title {title}"),
+            ),
+            ColumnSource::Index(index) => (
+                quote! { (#index < __texts.len()).then_some(#index) },
+                format!("Column at index {index}"),
+            ),
+        };
+
+        let binding = if let Some(inner_ty) = extract_option_inner_type(&field.ty) {
+            quote! {
+                let #field_name: ::std::option::Option<#inner_ty> = match #column {
+                    ::std::option::Option::Some(__col) => match __texts
+                        .get(__col)
+                        .cloned()
+                        .flatten()
+                        .filter(|text| !text.trim().is_empty())
+                    {
+                        ::std::option::Option::Some(__text) => ::std::option::Option::Some(
+                            <#inner_ty as ::std::str::FromStr>::from_str(&__text).map_err(|err| {
+                                ::wdpe::error::ElementError::InvalidTableCell {
+                                    row: row.row_index(),
+                                    col: __col,
+                                    text: __text.clone(),
+                                    message: err.to_string(),
+                                }
+                            })?,
+                        ),
+                        ::std::option::Option::None => ::std::option::Option::None,
+                    },
+                    ::std::option::Option::None => ::std::option::Option::None,
+                };
+            }
+        } else {
+            let field_ty = &field.ty;
+            quote! {
+                let #field_name: #field_ty = {
+                    let __col = (#column).ok_or_else(|| {
+                        ::wdpe::error::ElementError::NoSuchContent {
+                            element: ::wdpe::element::definition::ElementDefinition::id(
+                                &row.table_def(),
+                            )
+                            .to_string(),
+                            content: #column_desc.to_string(),
+                        }
+                    })?;
+                    let __text = __texts.get(__col).cloned().flatten().unwrap_or_default();
+                    <#field_ty as ::std::str::FromStr>::from_str(&__text).map_err(|err| {
+                        ::wdpe::error::ElementError::InvalidTableCell {
+                            row: row.row_index(),
+                            col: __col,
+                            text: __text.clone(),
+                            message: err.to_string(),
+                        }
+                    })?
+                };
+            }
+        };
+
+        field_bindings.push(binding);
+        field_names.push(field_name);
+    }
+
+    Ok(quote! {
+        impl<'body> ::wdpe::element::complex::sap_table::FromSapTable<'body> for #name {
+            fn from_table(
+                header: ::std::option::Option<&'body ::wdpe::element::complex::sap_table::SapTableHeader>,
+                row: &'body ::wdpe::element::complex::sap_table::SapTableRow,
+                parser: &'body ::wdpe::element::parser::ElementParser,
+            ) -> ::std::result::Result<Self, ::wdpe::error::WebDynproError> {
+                let __texts = row.try_row_into::<
+                    ::std::vec::Vec<::std::option::Option<::std::string::String>>
+                >(header, parser)?;
+                #(#field_bindings)*
+                ::std::result::Result::Ok(Self {
+                    #(#field_names,)*
+                })
+            }
+        }
+    })
+}
+
+/// Extract the column source from `#[wd_table(title = "...")]` or `#[wd_table(index = N)]`.
+fn extract_wd_table_source(attrs: &[syn::Attribute]) -> Result<Option<ColumnSource>> {
+    let mut source = None;
+    for attr in attrs {
+        if !attr.path().is_ident("wd_table") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            let parsed = if meta.path.is_ident("title") {
+                let lit: LitStr = meta.value()?.parse()?;
+                ColumnSource::Title(lit.value())
+            } else if meta.path.is_ident("index") {
+                let lit: LitInt = meta.value()?.parse()?;
+                ColumnSource::Index(lit.base10_parse()?)
+            } else {
+                return Err(meta.error("expected `title` or `index`"));
+            };
+            if source.is_some() {
+                return Err(meta.error("only one of `title` or `index` can be specified"));
+            }
+            source = Some(parsed);
+            Ok(())
+        })?;
+    }
+    Ok(source)
+}
+
+/// Extract the inner type `T` from `Option<T>`. Returns `None` if the type
+/// is not a simple `Option<T>` path.
+fn extract_option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    if let syn::Type::Path(type_path) = ty {
+        let segment = type_path.path.segments.last()?;
+        if segment.ident == "Option"
+            && let syn::PathArguments::AngleBracketed(args) = &segment.arguments
+            && let Some(syn::GenericArgument::Type(inner)) = args.args.first()
+        {
+            return Some(inner);
+        }
+    }
+    None
+}